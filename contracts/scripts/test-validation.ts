@@ -19,7 +19,7 @@ import { expect } from 'chai';
 const idl = JSON.parse(fs.readFileSync(path.resolve(__dirname, '../target/idl/medinex_ai.json'), 'utf8'));
 
 // Constants
-const PROGRAM_ID = new PublicKey('MdNxToKenxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+const PROGRAM_ID = new PublicKey('MdNxToKenMASi45ub7Qe4ZE36UT5G6cU4ud8Fhhe4de');
 
 // Helper function to create a connection to a Solana network
 function getConnection(network: string = 'http://localhost:8899'): Connection {
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::token::{MdnxToken, DEFAULT_MINT_COOLDOWN_SECONDS};
+use crate::contribution::BPS_DENOMINATOR;
+use crate::verification::VerificationType;
+
+/// Seed for the global program config PDA
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// Maximum number of per-type confidence bounds (one per verification type)
+pub const MAX_CONFIDENCE_BOUNDS: usize = 4;
+
+/// Program-wide parameters shared across modules
+#[account]
+pub struct Config {
+    /// Administrator allowed to update the config
+    pub admin: Pubkey,
+    
+    /// Global pause flag for instructions that read the config
+    pub paused: bool,
+    
+    /// Mint cooldown applied to tokens initialized without an explicit one
+    pub default_mint_cooldown: i64,
+    
+    /// Usage fee applied to newly registered models
+    pub default_usage_fee: u64,
+    
+    /// Verification fee applied to newly registered models
+    pub default_verification_fee: u64,
+    
+    /// Bump of the config PDA
+    pub bump: u8,
+    
+    /// Minimum seconds between a contributor's contributions (0 disables the limit)
+    pub contribution_cooldown: i64,
+    
+    /// Accepted confidence range per verification type (types without an entry accept any score)
+    pub confidence_bounds: Vec<ConfidenceBounds>,
+    
+    /// The canonical MDNX token whose authority the program trusts
+    /// (default until the admin sets it)
+    pub mdnx_token: Pubkey,
+}
+
+/// Confidence range accepted for a verification type
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ConfidenceBounds {
+    /// Verification type the bounds apply to
+    pub verification_type: VerificationType,
+    
+    /// Lowest accepted confidence score (basis points)
+    pub min_confidence: u16,
+    
+    /// Highest accepted confidence score (basis points)
+    pub max_confidence: u16,
+}
+
+impl Config {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        1 + // paused
+        8 + // default_mint_cooldown
+        8 + // default_usage_fee
+        8 + // default_verification_fee
+        1 + // bump
+        8 + // contribution_cooldown
+        4 + MAX_CONFIDENCE_BOUNDS * (4 + 2 + 2) + // confidence_bounds (Vec<ConfidenceBounds>)
+        32; // mdnx_token
+    
+    /// Whether a confidence score is within the bounds for the verification type
+    pub fn accepts_confidence(&self, verification_type: &VerificationType, confidence_score: u16) -> bool {
+        self.confidence_bounds
+            .iter()
+            .find(|bounds| bounds.verification_type == *verification_type)
+            .map_or(true, |bounds| {
+                confidence_score >= bounds.min_confidence && confidence_score <= bounds.max_confidence
+            })
+    }
+}
+
+/// Config operation implementations
+pub mod config_operations {
+    use super::*;
+    
+    /// Initialize the global config (once, by the deployer)
+    pub fn init_config(ctx: Context<InitConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        config.admin = ctx.accounts.admin.key();
+        config.paused = false;
+        config.default_mint_cooldown = DEFAULT_MINT_COOLDOWN_SECONDS;
+        config.default_usage_fee = 0;
+        config.default_verification_fee = 0;
+        config.bump = *ctx.bumps.get("config").unwrap();
+        config.contribution_cooldown = 0;
+        config.confidence_bounds = Vec::new();
+        config.mdnx_token = Pubkey::default();
+        
+        msg!("Config initialized with admin {}", config.admin);
+        Ok(())
+    }
+    
+    /// Update config parameters (admin only)
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_admin: Option<Pubkey>,
+        paused: Option<bool>,
+        default_mint_cooldown: Option<i64>,
+        default_usage_fee: Option<u64>,
+        default_verification_fee: Option<u64>,
+        contribution_cooldown: Option<i64>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        if let Some(admin) = new_admin {
+            config.admin = admin;
+        }
+        
+        if let Some(paused_val) = paused {
+            config.paused = paused_val;
+        }
+        
+        if let Some(cooldown) = default_mint_cooldown {
+            if cooldown < 0 {
+                return Err(ErrorCode::InvalidMintCooldown.into());
+            }
+            config.default_mint_cooldown = cooldown;
+        }
+        
+        if let Some(usage_fee) = default_usage_fee {
+            config.default_usage_fee = usage_fee;
+        }
+        
+        if let Some(verification_fee) = default_verification_fee {
+            config.default_verification_fee = verification_fee;
+        }
+        
+        if let Some(cooldown) = contribution_cooldown {
+            if cooldown < 0 {
+                return Err(ErrorCode::InvalidContributionCooldown.into());
+            }
+            config.contribution_cooldown = cooldown;
+        }
+        
+        msg!("Config updated");
+        Ok(())
+    }
+    
+    /// Name the canonical MDNX token (admin only)
+    pub fn set_canonical_token(ctx: Context<SetCanonicalToken>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        config.mdnx_token = ctx.accounts.mdnx_token.key();
+        
+        msg!("Canonical MDNX token set to {}", config.mdnx_token);
+        Ok(())
+    }
+    
+    /// Set the accepted confidence range for a verification type (admin only);
+    /// the full 0-10000 range removes the bounds
+    pub fn set_confidence_bounds(
+        ctx: Context<SetConfidenceBounds>,
+        verification_type: VerificationType,
+        min_confidence: u16,
+        max_confidence: u16,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        // Validate inputs
+        if min_confidence > max_confidence || max_confidence > BPS_DENOMINATOR {
+            return Err(ErrorCode::InvalidConfidenceBounds.into());
+        }
+        
+        config.confidence_bounds.retain(|bounds| bounds.verification_type != verification_type);
+        if min_confidence > 0 || max_confidence < BPS_DENOMINATOR {
+            config.confidence_bounds.push(ConfidenceBounds {
+                verification_type,
+                min_confidence,
+                max_confidence,
+            });
+        }
+        
+        msg!("Confidence bounds set to {}-{}", min_confidence, max_confidence);
+        Ok(())
+    }
+}
+
+/// Context for initializing the global config
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    /// The config PDA (can only be created once)
+    #[account(
+        init,
+        payer = admin,
+        space = Config::LEN,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Deployer, becomes the config admin (payer)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for updating the global config
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// The config PDA
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAccess
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Config admin
+    pub admin: Signer<'info>,
+}
+
+/// Context for setting a verification type's confidence bounds
+#[derive(Accounts)]
+pub struct SetConfidenceBounds<'info> {
+    /// The config PDA
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAccess
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Config admin
+    pub admin: Signer<'info>,
+}
+
+/// Context for naming the canonical MDNX token
+#[derive(Accounts)]
+pub struct SetCanonicalToken<'info> {
+    /// The config PDA
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAccess
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// The MDNX token to trust
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Config admin
+    pub admin: Signer<'info>,
+} 
\ No newline at end of file
@@ -1,5 +1,50 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
 use crate::errors::ErrorCode;
+use crate::model_registry::validate_sha256;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::token::{is_program_treasury, transfer_from_treasury, MdnxToken};
+
+/// Seed for the reviewer registry PDA
+pub const REVIEWER_REGISTRY_SEED: &[u8] = b"reviewer_registry";
+
+/// Maximum number of approved reviewers
+pub const MAX_REVIEWERS: usize = 10;
+
+/// Maximum number of co-contributors sharing a reward (bounded by compute budget)
+pub const MAX_CO_CONTRIBUTORS: usize = 5;
+
+/// Basis points representing a whole reward
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// Seed for the contribution bond vault token account
+pub const BOND_VAULT_SEED: &[u8] = b"bond_vault";
+
+/// Seed for the program-owned reward escrow PDA (one per reward mint)
+pub const REWARD_ESCROW_SEED: &[u8] = b"reward_escrow";
+
+/// Seed for a model's reward pool PDA, funded by the model owner to pay
+/// auto-approval rewards
+pub const MODEL_REWARD_POOL_SEED: &[u8] = b"model_reward_pool";
+
+/// Seed for contributor profile PDAs
+pub const CONTRIBUTOR_PROFILE_SEED: &[u8] = b"contributor_profile";
+
+/// Maximum length of a dispute reason
+pub const MAX_DISPUTE_REASON_LEN: usize = 128;
+
+/// Time a dispute stays open before it lapses and the approval stands (in seconds)
+pub const DISPUTE_RESOLUTION_PERIOD: i64 = 7 * 86400;
+
+/// Maximum number of contributions approved in one batch (bounded by compute budget)
+pub const MAX_BATCH_APPROVALS: usize = 5;
+
+/// Remaining accounts per batched approval: contribution, contributor
+/// profile, contributor token account and model contributor marker
+pub const ACCOUNTS_PER_BATCH_APPROVAL: usize = 4;
+
+/// Seed for per-(model, contributor) marker PDAs
+pub const MODEL_CONTRIBUTOR_SEED: &[u8] = b"model_contributor";
 
 /// Contribution data structure
 #[account]
@@ -16,8 +61,8 @@ pub struct Contribution {
     /// Contribution type (e.g., "data_contribution", "code_improvement", "validation")
     pub contribution_type: String,
     
-    /// Accuracy improvement (percentage as decimal 0.0-1.0)
-    pub accuracy_improvement: f64,
+    /// Accuracy improvement in basis points (0-10000)
+    pub accuracy_improvement: u16,
     
     /// Performance improvement details (JSON string)
     pub performance_improvement: String,
@@ -42,6 +87,57 @@ pub struct Contribution {
     
     /// Notes about the contribution (for reviewers)
     pub notes: String,
+    
+    /// Co-contributors and their reward shares (empty pays the contributor alone)
+    pub co_contributors: Vec<RewardShare>,
+    
+    /// The MDNX token whose bond vault holds this contribution's bond
+    pub mdnx_token: Pubkey,
+    
+    /// Bond locked by the contributor (refunded on approval, slashed on rejection)
+    pub bond_amount: u64,
+    
+    /// Increase in the model's computed accuracy applied on approval (basis points)
+    pub applied_accuracy_delta: u16,
+    
+    /// Who raised a dispute against the approved contribution
+    pub disputed_by: Option<Pubkey>,
+    
+    /// Why the contribution was disputed
+    pub dispute_reason: String,
+    
+    /// Improvement accepted by a partial approval, when lower than claimed
+    pub adjusted_accuracy_improvement: Option<u16>,
+    
+    /// Smallest reward the contributor will accept on approval
+    pub min_reward: Option<u64>,
+    
+    /// Mint the reward was paid in (set on approval; MDNX unless a partner token was used)
+    pub reward_mint: Option<Pubkey>,
+    
+    /// Part of the reward held in escrow until it is claimed
+    pub escrow_amount: u64,
+    
+    /// When the escrowed reward can be claimed (None if nothing is escrowed)
+    pub escrow_release_at: Option<i64>,
+    
+    /// When an unresolved dispute lapses; kept once it has lapsed, so the
+    /// contribution cannot be disputed again
+    pub dispute_expires_at: Option<i64>,
+    
+    /// Token account the reward was paid from (set on approval); clawbacks
+    /// and escrow refunds go back to it
+    pub reward_source: Option<Pubkey>,
+}
+
+/// A co-contributor's share of a contribution reward
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct RewardShare {
+    /// Co-contributor's wallet
+    pub contributor: Pubkey,
+    
+    /// Share of the reward in basis points
+    pub share_bps: u16,
 }
 
 /// Status of a contribution
@@ -58,6 +154,35 @@ pub enum ContributionStatus {
     
     /// Contribution has been rejected
     Rejected,
+    
+    /// Approved contribution whose validity has been challenged
+    Disputed,
+    
+    /// Disputed contribution whose approval was reversed
+    Reversed,
+}
+
+impl ContributionStatus {
+    /// Whether a contribution has reached a final decision
+    pub fn is_processed(&self) -> bool {
+        matches!(
+            self,
+            ContributionStatus::Approved |
+            ContributionStatus::Rejected |
+            ContributionStatus::Disputed |
+            ContributionStatus::Reversed
+        )
+    }
+    
+    /// Whether moving from this status to `next` is a legal review step
+    pub fn can_transition_to(&self, next: &ContributionStatus) -> bool {
+        matches!(
+            (self, next),
+            (ContributionStatus::Pending, ContributionStatus::InReview) |
+            (ContributionStatus::InReview, ContributionStatus::Approved) |
+            (ContributionStatus::InReview, ContributionStatus::Rejected)
+        )
+    }
 }
 
 impl Contribution {
@@ -66,7 +191,7 @@ impl Contribution {
         32 + // contributor
         256 + // description (string)
         32 + // contribution_type (string)
-        8 + // accuracy_improvement (f64)
+        2 + // accuracy_improvement (u16 basis points)
         256 + // performance_improvement (string)
         4 + // status (enum)
         8 + // reward_amount
@@ -74,13 +199,217 @@ impl Contribution {
         8 + // updated_at
         9 + // processed_at (Option<i64>)
         64 + // contribution_hash (string)
-        256; // notes (string)
+        256 + // notes (string)
+        4 + MAX_CO_CONTRIBUTORS * (32 + 2) + // co_contributors (Vec<RewardShare>)
+        32 + // mdnx_token
+        8 + // bond_amount
+        2 + // applied_accuracy_delta (u16 basis points)
+        33 + // disputed_by (Option<Pubkey>)
+        4 + MAX_DISPUTE_REASON_LEN + // dispute_reason (string)
+        3 + // adjusted_accuracy_improvement (Option<u16>)
+        9 + // min_reward (Option<u64>)
+        33 + // reward_mint (Option<Pubkey>)
+        8 + // escrow_amount
+        9 + // escrow_release_at (Option<i64>)
+        9 + // dispute_expires_at (Option<i64>)
+        33; // reward_source (Option<Pubkey>)
+    
+    /// Whether the contribution is under a dispute that has not yet lapsed
+    pub fn has_open_dispute(&self, timestamp: i64) -> bool {
+        self.status == ContributionStatus::Disputed
+            && self.dispute_expires_at.map_or(false, |expires_at| timestamp < expires_at)
+    }
+    
+    /// Restore the approval of a contribution whose dispute lapsed unresolved
+    pub fn lapse_expired_dispute(&mut self, timestamp: i64) {
+        if self.status == ContributionStatus::Disputed && !self.has_open_dispute(timestamp) {
+            self.status = ContributionStatus::Approved;
+        }
+    }
+    
+    /// Whether an approval reward meets the contributor's minimum, if any
+    pub fn accepts_reward(&self, reward_amount: u64) -> bool {
+        self.min_reward.map_or(true, |min_reward| reward_amount >= min_reward)
+    }
+    
+    /// Accuracy improvement accepted on approval (the claim, unless adjusted)
+    pub fn accepted_accuracy_improvement(&self) -> u16 {
+        self.adjusted_accuracy_improvement.unwrap_or(self.accuracy_improvement)
+    }
+    
+    /// Validate that co-contributor shares are bounded, distinct and sum to 100%
+    pub fn validate_reward_split(co_contributors: &[RewardShare]) -> Result<()> {
+        if co_contributors.is_empty() {
+            return Ok(());
+        }
+        
+        if co_contributors.len() > MAX_CO_CONTRIBUTORS {
+            return Err(ErrorCode::InvalidRewardSplit.into());
+        }
+        
+        let mut total_bps: u32 = 0;
+        for (index, share) in co_contributors.iter().enumerate() {
+            if share.share_bps == 0
+                || co_contributors[..index].iter().any(|other| other.contributor == share.contributor)
+            {
+                return Err(ErrorCode::InvalidRewardSplit.into());
+            }
+            total_bps += share.share_bps as u32;
+        }
+        
+        if total_bps != BPS_DENOMINATOR as u32 {
+            return Err(ErrorCode::InvalidRewardSplit.into());
+        }
+        
+        Ok(())
+    }
+}
+
+/// Registry of reviewers approved by the MDNX authority
+#[account]
+pub struct ReviewerRegistry {
+    /// The MDNX token whose authority manages this registry
+    pub mdnx_token: Pubkey,
+    
+    /// Approved reviewers
+    pub reviewers: Vec<Pubkey>,
+    
+    /// Bump of the reviewer registry PDA
+    pub bump: u8,
+}
+
+impl ReviewerRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mdnx_token
+        4 + (32 * MAX_REVIEWERS) + // reviewers (Vec<Pubkey>)
+        1; // bump
+    
+    /// Whether the given key is an approved reviewer
+    pub fn is_reviewer(&self, key: &Pubkey) -> bool {
+        self.reviewers.contains(key)
+    }
+}
+
+/// Per-contributor track record across all contributions
+#[account]
+pub struct ContributorProfile {
+    /// Contributor's public key
+    pub contributor: Pubkey,
+    
+    /// Number of approved contributions
+    pub approved_count: u64,
+    
+    /// Number of rejected contributions
+    pub rejected_count: u64,
+    
+    /// Total MDNX rewards earned
+    pub total_rewards: u64,
+    
+    /// Sum of accuracy improvements from approved contributions
+    pub total_accuracy_contributed: u64,
+    
+    /// Clawed-back rewards still owed, withheld from future rewards
+    pub reward_debt: u64,
+    
+    /// Bump of the contributor profile PDA
+    pub bump: u8,
+    
+    /// When the contributor last recorded a contribution
+    pub last_contribution_at: i64,
+}
+
+impl ContributorProfile {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // contributor
+        8 + // approved_count
+        8 + // rejected_count
+        8 + // total_rewards
+        8 + // total_accuracy_contributed (basis points)
+        8 + // reward_debt
+        1 + // bump
+        8; // last_contribution_at
+}
+
+/// Marks that a contributor has had a contribution approved for a model, so
+/// the model's unique contributor count is only bumped once per contributor
+#[account]
+pub struct ModelContributor {
+    /// The model the contributor improved
+    pub model: Pubkey,
+    
+    /// The contributor
+    pub contributor: Pubkey,
+    
+    /// When the contributor's first contribution to the model was approved
+    pub first_approved_at: i64,
+    
+    /// Bump of the marker PDA
+    pub bump: u8,
+}
+
+impl ModelContributor {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // model
+        32 + // contributor
+        8 + // first_approved_at
+        1; // bump
+}
+
+/// Emitted when a contribution is recorded
+#[event]
+pub struct ContributionRecorded {
+    pub contribution: Pubkey,
+    pub model: Pubkey,
+    pub contributor: Pubkey,
+    pub accuracy_improvement: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when a contribution is approved and rewarded
+#[event]
+pub struct ContributionApproved {
+    pub contribution: Pubkey,
+    pub model: Pubkey,
+    pub contributor: Pubkey,
+    pub reward_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a contribution is rejected
+#[event]
+pub struct ContributionRejected {
+    pub contribution: Pubkey,
+    pub model: Pubkey,
+    pub contributor: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when an approved contribution is disputed
+#[event]
+pub struct ContributionDisputed {
+    pub contribution: Pubkey,
+    pub model: Pubkey,
+    pub disputed_by: Pubkey,
+    pub reason: String,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a dispute is resolved
+#[event]
+pub struct DisputeResolved {
+    pub contribution: Pubkey,
+    pub model: Pubkey,
+    pub reversed: bool,
+    pub clawed_back: u64,
+    pub timestamp: i64,
 }
 
 /// Contribution operation implementations
 pub mod contribution_operations {
     use super::*;
-    use crate::model_registry::ModelRegistry;
+    use crate::model_registry::{model_operations, ModelLicense, ModelRegistry, ModelStatus};
     use anchor_spl::token;
     
     /// Record a new contribution to a model
@@ -88,27 +417,285 @@ pub mod contribution_operations {
         ctx: Context<crate::RecordContribution>,
         description: String,
         contribution_type: String,
-        accuracy_improvement: f64,
+        accuracy_improvement: u16,
         performance_improvement: String,
         contribution_hash: String,
+        co_contributors: Vec<RewardShare>,
+        bond_amount: u64,
+        min_reward: Option<u64>,
     ) -> Result<()> {
         let contribution = &mut ctx.accounts.contribution;
-        let model = &mut ctx.accounts.model;
+        let model = &mut ctx.accounts.model_registry;
         let contributor = &ctx.accounts.contributor;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
         // Validate input
-        if accuracy_improvement < 0.0 || accuracy_improvement > 1.0 {
-            return Err(ErrorCode::InvalidContributionImprovementValue.into());
+        if accuracy_improvement > BPS_DENOMINATOR {
+            return Err(ErrorCode::InvalidContributionValue.into());
+        }
+        
+        validate_sha256(&contribution_hash)?;
+        
+        Contribution::validate_reward_split(&co_contributors)?;
+        
+        // Returning contributors must respect the configured cooldown
+        let profile = &ctx.accounts.contributor_profile;
+        if profile.contributor != Pubkey::default() {
+            let cooldown = contribution_cooldown(&ctx.accounts.config)?;
+            let elapsed = current_timestamp
+                .checked_sub(profile.last_contribution_at)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            if elapsed < cooldown {
+                return Err(ErrorCode::RateLimited.into());
+            }
+        }
+        
+        // Small claims on models with an auto-approve policy skip review, as
+        // long as the fixed reward can be paid from the model's own reward pool;
+        // anything else, including an underfunded pool, is left pending for a reviewer
+        let auto_reward = model.auto_approve_reward;
+        let auto_reward_payable = auto_reward == 0 || (
+            model.reward_escrow_period == 0
+                && !ctx.accounts.mdnx_token.is_paused
+                && ctx.accounts.reward_pool
+                    .as_ref()
+                    .map_or(false, |reward_pool| reward_pool.amount >= auto_reward)
+        );
+        let auto_approve = model.auto_approves(accuracy_improvement)
+            && co_contributors.is_empty()
+            && !model.require_contribution_verification
+            && min_reward.map_or(true, |min_reward| auto_reward >= min_reward)
+            && auto_reward_payable;
+        
+        // Auto-approved contributions settle immediately, so hold no bond
+        let bond_amount = if auto_approve { 0 } else { bond_amount };
+        
+        // Lock the contributor's bond in the vault
+        if bond_amount > 0 {
+            // Token transfers are halted while the program is paused
+            if ctx.accounts.mdnx_token.is_paused {
+                return Err(ErrorCode::ProgramPaused.into());
+            }
+            
+            if bond_amount > ctx.accounts.contributor_token_account.amount {
+                return Err(ErrorCode::InsufficientTokenBalance.into());
+            }
+            
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.contributor_token_account.to_account_info(),
+                    to: ctx.accounts.bond_vault.to_account_info(),
+                    authority: contributor.to_account_info(),
+                },
+            );
+            
+            token::transfer(transfer_ctx, bond_amount)?;
+        }
+        
+        // Initialize contribution
+        init_contribution(
+            contribution,
+            model.key(),
+            contributor.key(),
+            ctx.accounts.mdnx_token.key(),
+            description,
+            contribution_type,
+            accuracy_improvement,
+            performance_improvement,
+            contribution_hash,
+            co_contributors,
+            bond_amount,
+            min_reward,
+            current_timestamp,
+        );
+        
+        // Create the contributor's profile on first contribution
+        let profile = &mut ctx.accounts.contributor_profile;
+        if profile.contributor == Pubkey::default() {
+            profile.contributor = contributor.key();
+            profile.bump = *ctx.bumps.get("contributor_profile").unwrap();
+        }
+        profile.last_contribution_at = current_timestamp;
+        
+        // Update model contribution count
+        model.contribution_count = model.contribution_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        emit!(ContributionRecorded {
+            contribution: contribution.key(),
+            model: model.key(),
+            contributor: contributor.key(),
+            accuracy_improvement,
+            timestamp: current_timestamp,
+        });
+        
+        // Settle an auto-approval straight away
+        if auto_approve {
+            let marker_info = ctx.accounts.model_contributor
+                .as_ref()
+                .ok_or(ErrorCode::MissingAutoApprovalAccounts)?
+                .to_account_info();
+            let first_approval = mark_model_contributor(
+                &marker_info,
+                model.key(),
+                contributor.key(),
+                contributor,
+                &ctx.accounts.system_program,
+                ctx.program_id,
+                current_timestamp,
+            )?;
+            
+            let withheld = apply_approval(
+                model,
+                contribution,
+                profile,
+                accuracy_improvement,
+                auto_reward,
+                ctx.accounts.mdnx_token.mint,
+                ctx.accounts.reward_pool.as_ref().map(|reward_pool| reward_pool.key()),
+                ctx.accounts.mdnx_token.mint,
+                first_approval,
+                current_timestamp,
+            )?;
+            
+            if auto_reward > withheld {
+                let reward_pool = ctx.accounts.reward_pool
+                    .as_ref()
+                    .ok_or(ErrorCode::InvalidTokenAccount)?;
+                transfer_from_reward_pool(
+                    &ctx.accounts.token_program,
+                    reward_pool,
+                    model.key(),
+                    ctx.accounts.contributor_token_account.to_account_info(),
+                    *ctx.bumps.get("reward_pool").unwrap(),
+                    auto_reward - withheld,
+                )?;
+            }
+            
+            emit!(ContributionApproved {
+                contribution: contribution.key(),
+                model: model.key(),
+                contributor: contributor.key(),
+                reward_amount: auto_reward,
+                timestamp: current_timestamp,
+            });
+            
+            msg!("Contribution auto-approved with reward {}", auto_reward);
         }
         
-        if contribution_hash.len() < 16 {
-            return Err(ErrorCode::InvalidDataHash.into());
+        msg!("Contribution recorded for model {}", model.key());
+        Ok(())
+    }
+    
+    /// Register a model and record its creator's first contribution atomically,
+    /// so the model never exists without it
+    pub fn register_model_with_contribution(
+        ctx: Context<crate::RegisterModelWithContribution>,
+        name: String,
+        description: String,
+        version: String,
+        model_type: String,
+        model_hash: String,
+        accuracy: u16,
+        performance_metrics: String,
+        storage_uri: String,
+        license: ModelLicense,
+        contribution_description: String,
+        contribution_type: String,
+        accuracy_improvement: u16,
+        performance_improvement: String,
+        contribution_hash: String,
+    ) -> Result<()> {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate input (the model's inputs are validated on registration)
+        if accuracy_improvement > BPS_DENOMINATOR {
+            return Err(ErrorCode::InvalidContributionValue.into());
         }
         
+        validate_sha256(&contribution_hash)?;
+        
+        model_operations::register_model_accounts(
+            &mut ctx.accounts.register, &ctx.bumps, name, description, version, model_type,
+            model_hash, accuracy, performance_metrics, storage_uri, license
+        )?;
+        
+        let model = &mut ctx.accounts.register.model_registry;
+        let contributor = &ctx.accounts.register.authority;
+        let contribution = &mut ctx.accounts.contribution;
+        
         // Initialize contribution
-        contribution.model = model.key();
-        contribution.contributor = contributor.key();
+        init_contribution(
+            contribution,
+            model.key(),
+            contributor.key(),
+            ctx.accounts.mdnx_token.key(),
+            contribution_description,
+            contribution_type,
+            accuracy_improvement,
+            performance_improvement,
+            contribution_hash,
+            Vec::new(),
+            0,
+            None,
+            current_timestamp,
+        );
+        
+        // Create the contributor's profile on first contribution
+        let profile = &mut ctx.accounts.contributor_profile;
+        if profile.contributor == Pubkey::default() {
+            profile.contributor = contributor.key();
+            profile.bump = *ctx.bumps.get("contributor_profile").unwrap();
+        }
+        profile.last_contribution_at = current_timestamp;
+        
+        // Update model contribution count
+        model.contribution_count = model.contribution_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        emit!(ContributionRecorded {
+            contribution: contribution.key(),
+            model: model.key(),
+            contributor: contributor.key(),
+            accuracy_improvement,
+            timestamp: current_timestamp,
+        });
+        
+        msg!("Model {} registered with its first contribution", model.key());
+        Ok(())
+    }
+    
+    /// Cooldown between a contributor's contributions, treating an
+    /// uninitialized config as no limit
+    fn contribution_cooldown(config: &AccountInfo) -> Result<i64> {
+        if config.data_is_empty() {
+            return Ok(0);
+        }
+        
+        Ok(Account::<crate::config::Config>::try_from(config)?.contribution_cooldown)
+    }
+    
+    /// Initialize a freshly created contribution as pending
+    fn init_contribution(
+        contribution: &mut Contribution,
+        model: Pubkey,
+        contributor: Pubkey,
+        mdnx_token: Pubkey,
+        description: String,
+        contribution_type: String,
+        accuracy_improvement: u16,
+        performance_improvement: String,
+        contribution_hash: String,
+        co_contributors: Vec<RewardShare>,
+        bond_amount: u64,
+        min_reward: Option<u64>,
+        current_timestamp: i64,
+    ) {
+        contribution.model = model;
+        contribution.contributor = contributor;
         contribution.description = description;
         contribution.contribution_type = contribution_type;
         contribution.accuracy_improvement = accuracy_improvement;
@@ -120,11 +707,67 @@ pub mod contribution_operations {
         contribution.processed_at = None;
         contribution.contribution_hash = contribution_hash;
         contribution.notes = String::new();
+        contribution.co_contributors = co_contributors;
+        contribution.mdnx_token = mdnx_token;
+        contribution.bond_amount = bond_amount;
+        contribution.applied_accuracy_delta = 0;
+        contribution.disputed_by = None;
+        contribution.dispute_reason = String::new();
+        contribution.adjusted_accuracy_improvement = None;
+        contribution.min_reward = min_reward;
+        contribution.reward_mint = None;
+        contribution.escrow_amount = 0;
+        contribution.escrow_release_at = None;
+        contribution.dispute_expires_at = None;
+        contribution.reward_source = None;
+    }
+    
+    /// Add an approved reviewer
+    pub fn add_reviewer(
+        ctx: Context<AddReviewer>,
+        reviewer: Pubkey,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.reviewer_registry;
         
-        // Update model contribution count
-        model.contribution_count += 1;
+        // Only authority (and multisig, if enabled) can manage reviewers
+        ctx.accounts.mdnx_token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
         
-        msg!("Contribution recorded for model {}", model.key());
+        if registry.reviewers.contains(&reviewer) || registry.reviewers.len() >= MAX_REVIEWERS {
+            return Err(ErrorCode::InvalidReviewerList.into());
+        }
+        
+        registry.mdnx_token = ctx.accounts.mdnx_token.key();
+        registry.bump = *ctx.bumps.get("reviewer_registry").unwrap();
+        registry.reviewers.push(reviewer);
+        
+        msg!("Reviewer added: {}", reviewer);
+        Ok(())
+    }
+    
+    /// Remove an approved reviewer
+    pub fn remove_reviewer(
+        ctx: Context<RemoveReviewer>,
+        reviewer: Pubkey,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.reviewer_registry;
+        
+        // Only authority (and multisig, if enabled) can manage reviewers
+        ctx.accounts.mdnx_token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        let index = registry.reviewers
+            .iter()
+            .position(|existing| *existing == reviewer)
+            .ok_or(ErrorCode::InvalidReviewerList)?;
+        
+        registry.reviewers.remove(index);
+        
+        msg!("Reviewer removed: {}", reviewer);
         Ok(())
     }
     
@@ -137,27 +780,75 @@ pub mod contribution_operations {
         let contribution = &mut ctx.accounts.contribution;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
+        // Processed contributions can't be moved back
+        if contribution.status.is_processed() {
+            return Err(ErrorCode::ContributionAlreadyProcessed.into());
+        }
+        
+        if !contribution.status.can_transition_to(&status) {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        // Reviews only open contributions for review; approvals and rejections
+        // go through approve/reject so rewards, counts and bonds are settled
+        if status.is_processed() {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
         // Update contribution
-        contribution.status = ContributionStatus::InReview;
+        contribution.status = status;
         contribution.notes = notes;
         contribution.updated_at = current_timestamp;
         
-        msg!("Contribution now under review");
+        msg!("Contribution review status updated");
         Ok(())
     }
     
     /// Approve a contribution and distribute rewards
-    pub fn approve_contribution(
-        ctx: Context<crate::ApproveContribution>,
+    ///
+    /// The reward is computed from the model's reward policy. When the
+    /// contribution lists co-contributors, their token accounts are passed as
+    /// remaining accounts in the same order as the shares.
+    pub fn approve_contribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, crate::ApproveContribution<'info>>,
+    ) -> Result<()> {
+        let accuracy_improvement = ctx.accounts.contribution.accuracy_improvement;
+        let reward_amount = ctx.accounts.model_registry.compute_reward(accuracy_improvement)?;
+        
+        settle_approval(ctx, accuracy_improvement, reward_amount)
+    }
+    
+    /// Approve a contribution with a reviewer-adjusted accuracy improvement
+    /// and reward, neither exceeding what a full approval would grant
+    pub fn approve_contribution_partial<'info>(
+        ctx: Context<'_, '_, '_, 'info, crate::ApproveContribution<'info>>,
+        accuracy_improvement: u16,
+        reward_amount: u64,
+    ) -> Result<()> {
+        let claimed_improvement = ctx.accounts.contribution.accuracy_improvement;
+        let full_reward = ctx.accounts.model_registry.compute_reward(claimed_improvement)?;
+        
+        // Validate inputs
+        if accuracy_improvement > claimed_improvement || reward_amount > full_reward {
+            return Err(ErrorCode::InvalidAdjustment.into());
+        }
+        
+        settle_approval(ctx, accuracy_improvement, reward_amount)
+    }
+    
+    /// Apply an approval: blend the accepted improvement into the model's
+    /// earned accuracy, update track records and pay out the reward
+    fn settle_approval<'info>(
+        ctx: Context<'_, '_, '_, 'info, crate::ApproveContribution<'info>>,
+        accuracy_improvement: u16,
         reward_amount: u64,
     ) -> Result<()> {
         let contribution = &mut ctx.accounts.contribution;
-        let model = &mut ctx.accounts.model;
+        let model = &mut ctx.accounts.model_registry;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
         // Ensure contribution was not already processed
-        if contribution.status == ContributionStatus::Approved || 
-           contribution.status == ContributionStatus::Rejected {
+        if contribution.status.is_processed() {
             return Err(ErrorCode::ContributionAlreadyProcessed.into());
         }
         
@@ -166,74 +857,1149 @@ pub mod contribution_operations {
             return Err(ErrorCode::ModelMismatch.into());
         }
         
-        // Update model accuracy if contribution improves it
-        if contribution.accuracy_improvement > 0.0 {
-            let new_accuracy = model.accuracy + 
-                (contribution.accuracy_improvement * (1.0 - model.accuracy));
+        // The authority must meet the contributor's minimum or reject instead
+        if !contribution.accepts_reward(reward_amount) {
+            return Err(ErrorCode::RewardBelowMinimum.into());
+        }
+        
+        // Models may demand proof before approving a claimed improvement
+        if model.require_contribution_verification {
+            let verification = ctx.accounts.verification
+                .as_ref()
+                .ok_or(ErrorCode::MissingVerification)?;
             
-            // Ensure accuracy doesn't exceed 1.0
-            model.accuracy = new_accuracy.min(1.0);
+            if verification.contribution != Some(contribution.key()) ||
+               !verification.is_valid_at(current_timestamp) {
+                return Err(ErrorCode::MissingVerification.into());
+            }
         }
         
-        // Update contribution status
-        contribution.status = ContributionStatus::Approved;
-        contribution.reward_amount = reward_amount;
-        contribution.processed_at = Some(current_timestamp);
-        contribution.updated_at = current_timestamp;
+        // A fresh marker means this is the contributor's first approval for the model
+        let marker = &mut ctx.accounts.model_contributor;
+        let first_approval = marker.model == Pubkey::default();
+        if first_approval {
+            marker.model = model.key();
+            marker.contributor = contribution.contributor;
+            marker.first_approved_at = current_timestamp;
+            marker.bump = *ctx.bumps.get("model_contributor").unwrap();
+        }
+        
+        let withheld = apply_approval(
+            model,
+            contribution,
+            &mut ctx.accounts.contributor_profile,
+            accuracy_improvement,
+            reward_amount,
+            ctx.accounts.treasury.mint,
+            Some(ctx.accounts.treasury.key()),
+            ctx.accounts.mdnx_token.mint,
+            first_approval,
+            current_timestamp,
+        )?;
+        let co_contributors = contribution.co_contributors.clone();
+        let bond_amount = contribution.bond_amount;
+        
+        // Token transfers are halted while the program is paused
+        if (reward_amount > 0 || bond_amount > 0) && ctx.accounts.mdnx_token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        // Surface an underfunded treasury as a typed error rather than an SPL failure
+        if reward_amount - withheld > ctx.accounts.treasury.amount {
+            return Err(ErrorCode::InsufficientTokenBalance.into());
+        }
+        
+        // No treasury-side signer approves program-owned treasury payouts, so
+        // they must stay within the limits the token authority set and go only
+        // to models it has verified
+        if reward_amount > withheld && is_program_treasury(&ctx.accounts.treasury) {
+            if !model.is_verified {
+                return Err(ErrorCode::ModelNotVerified.into());
+            }
+            ctx.accounts.mdnx_token.record_reward_payout(reward_amount - withheld, current_timestamp)?;
+        }
+        
+        // Refund the bond to the contributor; bonds are always MDNX, so
+        // partner-token rewards need a separate MDNX account for it
+        if bond_amount > 0 {
+            let bond_destination = match ctx.accounts.contributor_bond_account.as_ref() {
+                Some(bond_account) => bond_account.to_account_info(),
+                None => {
+                    if ctx.accounts.contributor_token_account.mint != ctx.accounts.mdnx_token.mint {
+                        return Err(ErrorCode::InvalidTokenAccount.into());
+                    }
+                    ctx.accounts.contributor_token_account.to_account_info()
+                }
+            };
+            
+            release_bond(
+                &ctx.accounts.token_program,
+                &ctx.accounts.bond_vault,
+                bond_destination,
+                ctx.accounts.mdnx_token.key(),
+                *ctx.bumps.get("bond_vault").unwrap(),
+                bond_amount,
+            )?;
+            
+            msg!("Refunded {} MDNX bond to contributor", bond_amount);
+        }
+        
+        // Models with an escrow period hold the reward back until it can be claimed
+        let escrow_period = model.reward_escrow_period;
+        let treasury_bump = ctx.accounts.mdnx_token.treasury_bump;
         
         // Transfer tokens if reward amount is greater than zero
         if reward_amount > 0 {
-            // Transfer tokens from treasury to contributor
-            let treasury = &ctx.accounts.treasury;
-            let contributor_token_account = &ctx.accounts.contributor_token_account;
-            let token_program = &ctx.accounts.token_program;
-            let authority = &ctx.accounts.authority;
-            
-            // Create CPI context for token transfer
-            let transfer_ctx = CpiContext::new(
-                token_program.to_account_info(),
-                token::Transfer {
-                    from: treasury.to_account_info(),
-                    to: contributor_token_account.to_account_info(),
-                    authority: authority.to_account_info(),
-                },
-            );
             
-            // Execute token transfer
-            token::transfer(transfer_ctx, reward_amount)?;
+            if co_contributors.is_empty() {
+                // Transfer tokens from treasury to contributor, or into escrow
+                if reward_amount > withheld && escrow_period > 0 {
+                    let reward_escrow = ctx.accounts.reward_escrow
+                        .as_ref()
+                        .ok_or(ErrorCode::MissingRewardEscrow)?;
+                    let payout = reward_amount - withheld;
+                    
+                    transfer_reward(
+                        &ctx.accounts.token_program,
+                        &ctx.accounts.treasury,
+                        &ctx.accounts.authority,
+                        reward_escrow.to_account_info(),
+                        treasury_bump,
+                        payout,
+                    )?;
+                    
+                    contribution.escrow_amount = payout;
+                    contribution.escrow_release_at = Some(
+                        current_timestamp
+                            .checked_add(escrow_period)
+                            .ok_or(ErrorCode::ArithmeticOverflow)?
+                    );
+                } else if reward_amount > withheld {
+                    transfer_reward(
+                        &ctx.accounts.token_program,
+                        &ctx.accounts.treasury,
+                        &ctx.accounts.authority,
+                        ctx.accounts.contributor_token_account.to_account_info(),
+                        treasury_bump,
+                        reward_amount - withheld,
+                    )?;
+                }
+            } else {
+                // Split rewards are paid out directly; escrow holds a single claim
+                if escrow_period > 0 {
+                    return Err(ErrorCode::InvalidRewardSplit.into());
+                }
+                
+                if ctx.remaining_accounts.len() != co_contributors.len() {
+                    return Err(ErrorCode::InvalidRewardSplit.into());
+                }
+                
+                let mut distributed: u64 = 0;
+                for (index, (share, destination)) in co_contributors
+                    .iter()
+                    .zip(ctx.remaining_accounts.iter())
+                    .enumerate()
+                {
+                    let destination_account = Account::<TokenAccount>::try_from(destination)?;
+                    if destination_account.owner != share.contributor
+                        || destination_account.mint != ctx.accounts.treasury.mint
+                    {
+                        return Err(ErrorCode::InvalidTokenAccount.into());
+                    }
+                    
+                    // The last share absorbs any rounding remainder
+                    let amount = if index + 1 == co_contributors.len() {
+                        reward_amount
+                            .checked_sub(distributed)
+                            .ok_or(ErrorCode::ArithmeticOverflow)?
+                    } else {
+                        ((reward_amount as u128) * (share.share_bps as u128)
+                            / (BPS_DENOMINATOR as u128)) as u64
+                    };
+                    distributed = distributed
+                        .checked_add(amount)
+                        .ok_or(ErrorCode::ArithmeticOverflow)?;
+                    
+                    transfer_reward(
+                        &ctx.accounts.token_program,
+                        &ctx.accounts.treasury,
+                        &ctx.accounts.authority,
+                        destination.clone(),
+                        treasury_bump,
+                        amount,
+                    )?;
+                }
+            }
             
-            msg!("Transferred {} MDNX tokens to contributor", reward_amount);
+            msg!("Transferred {} reward tokens of mint {} to contributors", reward_amount, ctx.accounts.treasury.mint);
         }
         
-        msg!("Contribution approved for model {}", model.key());
+        emit!(ContributionApproved {
+            contribution: ctx.accounts.contribution.key(),
+            model: ctx.accounts.model_registry.key(),
+            contributor: ctx.accounts.contribution.contributor,
+            reward_amount,
+            timestamp: current_timestamp,
+        });
+        
+        msg!("Contribution approved for model {}", ctx.accounts.model_registry.key());
         Ok(())
     }
     
-    /// Reject a contribution
-    pub fn reject_contribution(
-        ctx: Context<RejectContribution>,
-        rejection_reason: String,
-    ) -> Result<()> {
-        let contribution = &mut ctx.accounts.contribution;
-        let current_timestamp = Clock::get()?.unix_timestamp;
+    /// Record an approval on the model, contribution and contributor profile,
+    /// returning the part of the reward withheld to repay reward debt. Reward
+    /// totals and debt are kept in MDNX, so partner-token rewards skip them.
+    fn apply_approval(
+        model: &mut ModelRegistry,
+        contribution: &mut Contribution,
+        profile: &mut ContributorProfile,
+        accuracy_improvement: u16,
+        reward_amount: u64,
+        reward_mint: Pubkey,
+        reward_source: Option<Pubkey>,
+        mdnx_mint: Pubkey,
+        first_approval: bool,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        let mdnx_reward = if reward_mint == mdnx_mint { reward_amount } else { 0 };
+        model.total_rewards_distributed = model.total_rewards_distributed
+            .checked_add(mdnx_reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        model.approved_contribution_count = model.approved_contribution_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        if first_approval {
+            model.unique_contributor_count = model.unique_contributor_count
+                .checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
         
-        // Ensure contribution was not already processed
-        if contribution.status == ContributionStatus::Approved || 
-           contribution.status == ContributionStatus::Rejected {
-            return Err(ErrorCode::ContributionAlreadyProcessed.into());
+        // Update earned accuracy if contribution improves it; the
+        // self-reported model.accuracy is left untouched
+        if accuracy_improvement > 0 {
+            let improvement = accuracy_improvement.min(BPS_DENOMINATOR) as u32;
+            let current = model.computed_accuracy.min(BPS_DENOMINATOR) as u32;
+            let denominator = BPS_DENOMINATOR as u32;
+            let new_accuracy = current + improvement * (denominator - current) / denominator;
+            
+            // Ensure accuracy stays within [0, 10000] basis points
+            let new_accuracy = new_accuracy.min(denominator);
+            model.computed_accuracy = new_accuracy as u16;
+            contribution.applied_accuracy_delta = (new_accuracy - current) as u16;
+            model.accuracy_contribution_count = model.accuracy_contribution_count
+                .checked_add(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
         }
         
-        // Update contribution
-        contribution.status = ContributionStatus::Rejected;
-        contribution.notes = rejection_reason;
+        // Record any reviewer adjustment to the claimed improvement
+        if accuracy_improvement != contribution.accuracy_improvement {
+            contribution.adjusted_accuracy_improvement = Some(accuracy_improvement);
+        }
+        
+        // Update contribution status
+        contribution.status = ContributionStatus::Approved;
+        contribution.reward_amount = reward_amount;
+        contribution.reward_mint = Some(reward_mint);
+        contribution.reward_source = reward_source;
         contribution.processed_at = Some(current_timestamp);
         contribution.updated_at = current_timestamp;
         
-        msg!("Contribution rejected");
+        // Update the contributor's track record
+        profile.approved_count = profile.approved_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        profile.total_rewards = profile.total_rewards
+            .checked_add(mdnx_reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        profile.total_accuracy_contributed = profile.total_accuracy_contributed
+            .checked_add(accuracy_improvement as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Rewards clawed back from reversed contributions are withheld from a
+        // sole contributor's payout until repaid
+        let withheld = if contribution.co_contributors.is_empty() {
+            mdnx_reward.min(profile.reward_debt)
+        } else {
+            0
+        };
+        profile.reward_debt -= withheld;
+        
+        Ok(withheld)
+    }
+    
+    /// Approve several pending contributions to one model atomically. Each
+    /// reward may be trimmed below, but never above, a full approval's reward.
+    /// Remaining accounts hold ACCOUNTS_PER_BATCH_APPROVAL entries per
+    /// contribution: the contribution, its contributor profile, the
+    /// contributor's token account and the model contributor marker PDA.
+    pub fn approve_contributions_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveContributionsBatch<'info>>,
+        rewards: Vec<u64>,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if rewards.is_empty()
+            || rewards.len() > MAX_BATCH_APPROVALS
+            || ctx.remaining_accounts.len() != rewards.len() * ACCOUNTS_PER_BATCH_APPROVAL
+        {
+            return Err(ErrorCode::InvalidBatchSize.into());
+        }
+        
+        // Batches carry no verification accounts
+        if model.require_contribution_verification {
+            return Err(ErrorCode::MissingVerification.into());
+        }
+        
+        // The program treasury only pays models the token authority has verified
+        let program_treasury = is_program_treasury(&ctx.accounts.treasury);
+        if program_treasury && !model.is_verified {
+            return Err(ErrorCode::ModelNotVerified.into());
+        }
+        
+        // Batches carry no escrow account either
+        if model.reward_escrow_period > 0 {
+            return Err(ErrorCode::MissingRewardEscrow.into());
+        }
+        
+        let mdnx_token = &mut ctx.accounts.mdnx_token;
+        let bond_vault_bump = *ctx.bumps.get("bond_vault").unwrap();
+        let mut treasury_balance = ctx.accounts.treasury.amount;
+        
+        for (accounts, &reward_amount) in ctx.remaining_accounts
+            .chunks(ACCOUNTS_PER_BATCH_APPROVAL)
+            .zip(rewards.iter())
+        {
+            let mut contribution = Account::<Contribution>::try_from(&accounts[0])?;
+            let mut profile = Account::<ContributorProfile>::try_from(&accounts[1])?;
+            let contributor_token_account = Account::<TokenAccount>::try_from(&accounts[2])?;
+            
+            if contribution.status.is_processed() {
+                return Err(ErrorCode::ContributionAlreadyProcessed.into());
+            }
+            
+            if contribution.model != model.key() || contribution.mdnx_token != mdnx_token.key() {
+                return Err(ErrorCode::ModelMismatch.into());
+            }
+            
+            // Split rewards need a token account per co-contributor; approve those singly
+            if !contribution.co_contributors.is_empty() {
+                return Err(ErrorCode::InvalidRewardSplit.into());
+            }
+            
+            let expected_profile = Pubkey::create_program_address(
+                &[CONTRIBUTOR_PROFILE_SEED, contribution.contributor.as_ref(), &[profile.bump]],
+                ctx.program_id,
+            ).map_err(|_| anchor_lang::error::ErrorCode::ConstraintSeeds)?;
+            if profile.key() != expected_profile {
+                return Err(anchor_lang::error::ErrorCode::ConstraintSeeds.into());
+            }
+            
+            if contributor_token_account.owner != contribution.contributor
+                || contributor_token_account.mint != mdnx_token.mint {
+                return Err(ErrorCode::InvalidTokenAccount.into());
+            }
+            
+            let accuracy_improvement = contribution.accuracy_improvement;
+            if reward_amount > model.compute_reward(accuracy_improvement)? {
+                return Err(ErrorCode::InvalidAdjustment.into());
+            }
+            
+            if !contribution.accepts_reward(reward_amount) {
+                return Err(ErrorCode::RewardBelowMinimum.into());
+            }
+            
+            let first_approval = mark_model_contributor(
+                &accounts[3],
+                model.key(),
+                contribution.contributor,
+                &ctx.accounts.authority,
+                &ctx.accounts.system_program,
+                ctx.program_id,
+                current_timestamp,
+            )?;
+            
+            let withheld = apply_approval(
+                model,
+                &mut contribution,
+                &mut profile,
+                accuracy_improvement,
+                reward_amount,
+                mdnx_token.mint,
+                Some(ctx.accounts.treasury.key()),
+                mdnx_token.mint,
+                first_approval,
+                current_timestamp,
+            )?;
+            let bond_amount = contribution.bond_amount;
+            let payout = reward_amount - withheld;
+            
+            // Token transfers are halted while the program is paused
+            if (reward_amount > 0 || bond_amount > 0) && mdnx_token.is_paused {
+                return Err(ErrorCode::ProgramPaused.into());
+            }
+            
+            // The treasury must cover the whole batch
+            treasury_balance = treasury_balance
+                .checked_sub(payout)
+                .ok_or(ErrorCode::InsufficientTokenBalance)?;
+            
+            if bond_amount > 0 {
+                release_bond(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.bond_vault,
+                    accounts[2].clone(),
+                    mdnx_token.key(),
+                    bond_vault_bump,
+                    bond_amount,
+                )?;
+            }
+            
+            if payout > 0 {
+                // Program-owned treasury payouts stay within the authority's limits
+                if program_treasury {
+                    mdnx_token.record_reward_payout(payout, current_timestamp)?;
+                }
+                
+                transfer_reward(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.treasury,
+                    &ctx.accounts.authority,
+                    accounts[2].clone(),
+                    mdnx_token.treasury_bump,
+                    payout,
+                )?;
+            }
+            
+            // Persist before the next item so a repeated contribution is seen as processed
+            contribution.exit(ctx.program_id)?;
+            profile.exit(ctx.program_id)?;
+            
+            emit!(ContributionApproved {
+                contribution: contribution.key(),
+                model: model.key(),
+                contributor: contribution.contributor,
+                reward_amount,
+                timestamp: current_timestamp,
+            });
+        }
+        
+        msg!("Approved {} contributions for model {}", rewards.len(), model.key());
+        Ok(())
+    }
+    
+    /// Create the model contributor marker if it does not exist yet, returning
+    /// whether this is the contributor's first approval for the model
+    fn mark_model_contributor<'info>(
+        marker_info: &AccountInfo<'info>,
+        model: Pubkey,
+        contributor: Pubkey,
+        payer: &Signer<'info>,
+        system_program: &Program<'info, System>,
+        program_id: &Pubkey,
+        current_timestamp: i64,
+    ) -> Result<bool> {
+        let (expected_marker, bump) = Pubkey::find_program_address(
+            &[MODEL_CONTRIBUTOR_SEED, model.as_ref(), contributor.as_ref()],
+            program_id,
+        );
+        if marker_info.key() != expected_marker {
+            return Err(anchor_lang::error::ErrorCode::ConstraintSeeds.into());
+        }
+        
+        // An existing marker means the contributor was already counted
+        if !marker_info.data_is_empty() {
+            Account::<ModelContributor>::try_from(marker_info)?;
+            return Ok(false);
+        }
+        
+        let seeds = &[
+            MODEL_CONTRIBUTOR_SEED,
+            model.as_ref(),
+            contributor.as_ref(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let create_ctx = CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::CreateAccount {
+                from: payer.to_account_info(),
+                to: marker_info.clone(),
+            },
+            signer_seeds,
+        );
+        anchor_lang::system_program::create_account(
+            create_ctx,
+            Rent::get()?.minimum_balance(ModelContributor::LEN),
+            ModelContributor::LEN as u64,
+            program_id,
+        )?;
+        
+        let mut marker = Account::<ModelContributor>::try_from_unchecked(marker_info)?;
+        marker.model = model;
+        marker.contributor = contributor;
+        marker.first_approved_at = current_timestamp;
+        marker.bump = bump;
+        marker.exit(program_id)?;
+        
+        Ok(true)
+    }
+    
+    /// Transfer a reward from the treasury, signed by the treasury PDA or,
+    /// for an externally owned treasury, by the approving authority
+    fn transfer_reward<'info>(
+        token_program: &Program<'info, Token>,
+        treasury: &Account<'info, TokenAccount>,
+        authority: &Signer<'info>,
+        destination: AccountInfo<'info>,
+        treasury_bump: u8,
+        amount: u64,
+    ) -> Result<()> {
+        transfer_from_treasury(
+            token_program.to_account_info(),
+            treasury,
+            Some(authority.to_account_info()),
+            destination,
+            treasury_bump,
+            amount,
+        )
+    }
+    
+    /// Move a bond out of the vault, signed by the vault PDA
+    fn release_bond<'info>(
+        token_program: &Program<'info, Token>,
+        bond_vault: &Account<'info, TokenAccount>,
+        destination: AccountInfo<'info>,
+        mdnx_token: Pubkey,
+        bump: u8,
+        amount: u64,
+    ) -> Result<()> {
+        let seeds = &[
+            BOND_VAULT_SEED,
+            mdnx_token.as_ref(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: bond_vault.to_account_info(),
+                to: destination,
+                authority: bond_vault.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, amount)
+    }
+    
+    /// Move tokens out of a model's reward pool, signed by the pool PDA
+    fn transfer_from_reward_pool<'info>(
+        token_program: &Program<'info, Token>,
+        reward_pool: &Account<'info, TokenAccount>,
+        model: Pubkey,
+        destination: AccountInfo<'info>,
+        bump: u8,
+        amount: u64,
+    ) -> Result<()> {
+        let seeds = &[
+            MODEL_REWARD_POOL_SEED,
+            model.as_ref(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: reward_pool.to_account_info(),
+                to: destination,
+                authority: reward_pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, amount)
+    }
+    
+    /// Move an escrowed reward out of the escrow, signed by the escrow PDA
+    fn release_escrowed_reward<'info>(
+        token_program: &Program<'info, Token>,
+        reward_escrow: &Account<'info, TokenAccount>,
+        destination: AccountInfo<'info>,
+        bump: u8,
+        amount: u64,
+    ) -> Result<()> {
+        let seeds = &[
+            REWARD_ESCROW_SEED,
+            reward_escrow.mint.as_ref(),
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: reward_escrow.to_account_info(),
+                to: destination,
+                authority: reward_escrow.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, amount)
+    }
+    
+    /// Withdraw a contribution that has not been processed yet, closing its
+    /// account and refunding any bond
+    pub fn withdraw_contribution(
+        ctx: Context<WithdrawContribution>,
+    ) -> Result<()> {
+        let contribution = &ctx.accounts.contribution;
+        let model = &mut ctx.accounts.model_registry;
+        
+        // Only pending or in-review contributions can be withdrawn
+        if contribution.status.is_processed() {
+            return Err(ErrorCode::ContributionAlreadyProcessed.into());
+        }
+        
+        // Refund the bond to the contributor
+        let bond_amount = contribution.bond_amount;
+        if bond_amount > 0 {
+            // Token transfers are halted while the program is paused
+            if ctx.accounts.mdnx_token.is_paused {
+                return Err(ErrorCode::ProgramPaused.into());
+            }
+            
+            release_bond(
+                &ctx.accounts.token_program,
+                &ctx.accounts.bond_vault,
+                ctx.accounts.contributor_token_account.to_account_info(),
+                ctx.accounts.mdnx_token.key(),
+                *ctx.bumps.get("bond_vault").unwrap(),
+                bond_amount,
+            )?;
+        }
+        
+        // Update model contribution count
+        model.contribution_count = model.contribution_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        msg!("Contribution withdrawn from model {}", model.key());
+        Ok(())
+    }
+    
+    /// Move a pending contribution recorded against the wrong model, signed by
+    /// the contributor or by both model authorities
+    pub fn reassign_contribution(
+        ctx: Context<ReassignContribution>,
+    ) -> Result<()> {
+        let contribution = &mut ctx.accounts.contribution;
+        let old_model = &mut ctx.accounts.old_model;
+        let new_model = &mut ctx.accounts.new_model;
+        let signer = ctx.accounts.signer.key();
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only contributions nobody has started reviewing can move
+        if contribution.status != ContributionStatus::Pending {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        if new_model.status == ModelStatus::Archived {
+            return Err(ErrorCode::ModelArchived.into());
+        }
+        
+        // Without the contributor, both model owners must agree
+        if signer != contribution.contributor {
+            let target_authority = ctx.accounts.target_authority
+                .as_ref()
+                .map(|authority| authority.key());
+            if signer != old_model.authority || target_authority != Some(new_model.authority) {
+                return Err(ErrorCode::UnauthorizedAccess.into());
+            }
+        }
+        
+        old_model.contribution_count = old_model.contribution_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        new_model.contribution_count = new_model.contribution_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        contribution.model = new_model.key();
+        contribution.updated_at = current_timestamp;
+        
+        msg!("Contribution {} reassigned from model {} to {}", contribution.key(), old_model.key(), new_model.key());
+        Ok(())
+    }
+    
+    /// Reject a contribution
+    pub fn reject_contribution(
+        ctx: Context<RejectContribution>,
+        rejection_reason: String,
+        refund_bond: bool,
+    ) -> Result<()> {
+        let contribution = &mut ctx.accounts.contribution;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Ensure contribution was not already processed
+        if contribution.status.is_processed() {
+            return Err(ErrorCode::ContributionAlreadyProcessed.into());
+        }
+        
+        // Ensure model matches
+        if contribution.model != ctx.accounts.model_registry.key() {
+            return Err(ErrorCode::ModelMismatch.into());
+        }
+        
+        // Rejected contributions keep counting towards the model's
+        // contribution_count, which tracks submissions, and never reach
+        // approved_contribution_count
+        
+        // Update contribution
+        contribution.status = ContributionStatus::Rejected;
+        contribution.notes = rejection_reason.clone();
+        contribution.processed_at = Some(current_timestamp);
+        contribution.updated_at = current_timestamp;
+        let bond_amount = contribution.bond_amount;
+        
+        // Update the contributor's track record
+        let profile = &mut ctx.accounts.contributor_profile;
+        profile.rejected_count = profile.rejected_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Refund the bond to the contributor for good-faith submissions,
+        // otherwise slash it to the treasury
+        if bond_amount > 0 {
+            // Token transfers are halted while the program is paused
+            if ctx.accounts.mdnx_token.is_paused {
+                return Err(ErrorCode::ProgramPaused.into());
+            }
+            
+            let destination = if refund_bond {
+                ctx.accounts.contributor_token_account
+                    .as_ref()
+                    .ok_or(ErrorCode::InvalidTokenAccount)?
+                    .to_account_info()
+            } else {
+                ctx.accounts.treasury.to_account_info()
+            };
+            
+            release_bond(
+                &ctx.accounts.token_program,
+                &ctx.accounts.bond_vault,
+                destination,
+                ctx.accounts.mdnx_token.key(),
+                *ctx.bumps.get("bond_vault").unwrap(),
+                bond_amount,
+            )?;
+            
+            if refund_bond {
+                msg!("Refunded {} MDNX bond to contributor", bond_amount);
+            } else {
+                msg!("Slashed {} MDNX bond to treasury", bond_amount);
+            }
+        }
+        
+        emit!(ContributionRejected {
+            contribution: ctx.accounts.contribution.key(),
+            model: ctx.accounts.model_registry.key(),
+            contributor: ctx.accounts.contribution.contributor,
+            reason: rejection_reason,
+            timestamp: current_timestamp,
+        });
+        
+        msg!("Contribution rejected");
+        Ok(())
+    }
+    
+    /// Challenge an approved contribution, freezing it until the dispute is
+    /// resolved or lapses after `DISPUTE_RESOLUTION_PERIOD` (model owner or
+    /// approved reviewer only)
+    pub fn dispute_contribution(
+        ctx: Context<DisputeContribution>,
+        reason: String,
+    ) -> Result<()> {
+        let contribution = &mut ctx.accounts.contribution;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only approved contributions can be disputed
+        contribution.lapse_expired_dispute(current_timestamp);
+        if contribution.status != ContributionStatus::Approved {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        // A dispute left to lapse cannot be raised again to keep the reward frozen
+        if contribution.dispute_expires_at.is_some() {
+            return Err(ErrorCode::DisputeLapsed.into());
+        }
+        
+        // Validate inputs
+        if reason.len() > MAX_DISPUTE_REASON_LEN {
+            return Err(ErrorCode::FieldTooLong.into());
+        }
+        
+        let expires_at = current_timestamp
+            .checked_add(DISPUTE_RESOLUTION_PERIOD)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        contribution.status = ContributionStatus::Disputed;
+        contribution.disputed_by = Some(ctx.accounts.disputer.key());
+        contribution.dispute_reason = reason.clone();
+        contribution.dispute_expires_at = Some(expires_at);
+        contribution.updated_at = current_timestamp;
+        
+        emit!(ContributionDisputed {
+            contribution: contribution.key(),
+            model: contribution.model,
+            disputed_by: ctx.accounts.disputer.key(),
+            reason,
+            expires_at,
+            timestamp: current_timestamp,
+        });
+        
+        msg!("Contribution disputed: {}", contribution.key());
+        Ok(())
+    }
+    
+    /// Resolve a dispute, either restoring the approval or reversing its
+    /// accuracy boost and optionally clawing back the reward
+    ///
+    /// Clawed-back rewards become a debt on the contributor's profile, withheld
+    /// from their future rewards.
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        reverse: bool,
+        claw_back: bool,
+    ) -> Result<()> {
+        let contribution = &mut ctx.accounts.contribution;
+        let model = &mut ctx.accounts.model_registry;
+        let profile = &mut ctx.accounts.contributor_profile;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        if contribution.status != ContributionStatus::Disputed {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        // An unresolved dispute lapses once its resolution period ends
+        if !contribution.has_open_dispute(current_timestamp) {
+            return Err(ErrorCode::DisputeLapsed.into());
+        }
+        
+        // A dismissed dispute cannot claw anything back
+        if claw_back && !reverse {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        // Reward debt is kept in MDNX, so partner-token rewards cannot be clawed back
+        if claw_back && contribution.reward_mint != Some(ctx.accounts.mdnx_token.mint) {
+            return Err(ErrorCode::RewardNotClawable.into());
+        }
+        
+        let mut clawed_back = 0;
+        if reverse {
+            // Undo the accuracy boost applied on approval
+            let delta = contribution.applied_accuracy_delta;
+            model.approved_contribution_count = model.approved_contribution_count
+                .checked_sub(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            if delta > 0 {
+                model.computed_accuracy = model.computed_accuracy.saturating_sub(delta);
+                model.accuracy_contribution_count = model.accuracy_contribution_count
+                    .checked_sub(1)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+            }
+            contribution.applied_accuracy_delta = 0;
+            
+            profile.total_accuracy_contributed = profile.total_accuracy_contributed
+                .saturating_sub(contribution.accepted_accuracy_improvement() as u64);
+            
+            // Only the part paid out is clawed back; an escrowed part is
+            // refunded through cancel_reward_escrow instead
+            if claw_back {
+                clawed_back = contribution.reward_amount
+                    .checked_sub(contribution.escrow_amount)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                profile.reward_debt = profile.reward_debt
+                    .checked_add(clawed_back)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                profile.total_rewards = profile.total_rewards.saturating_sub(clawed_back);
+                model.total_rewards_distributed = model.total_rewards_distributed.saturating_sub(clawed_back);
+            }
+            
+            contribution.status = ContributionStatus::Reversed;
+        } else {
+            contribution.status = ContributionStatus::Approved;
+        }
+        contribution.dispute_expires_at = None;
+        contribution.updated_at = current_timestamp;
+        
+        emit!(DisputeResolved {
+            contribution: contribution.key(),
+            model: model.key(),
+            reversed: reverse,
+            clawed_back,
+            timestamp: current_timestamp,
+        });
+        
+        msg!("Dispute resolved for contribution {} (reversed: {})", contribution.key(), reverse);
+        Ok(())
+    }
+    
+    /// Return part of an overpaid reward from the contributor to the account it
+    /// was paid from. The contributor, or a delegate they approved, must co-sign.
+    pub fn claw_back_reward(
+        ctx: Context<ClawBackReward>,
+        amount: u64,
+    ) -> Result<()> {
+        let contribution = &mut ctx.accounts.contribution;
+        let model = &mut ctx.accounts.model_registry;
+        let profile = &mut ctx.accounts.contributor_profile;
+        let contributor_token_account = &ctx.accounts.contributor_token_account;
+        let signer = ctx.accounts.contributor_signer.key();
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only approved contributions have a reward to correct
+        contribution.lapse_expired_dispute(current_timestamp);
+        if contribution.status != ContributionStatus::Approved {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        // An escrowed reward never reached the contributor; cancel the escrow instead
+        if contribution.escrow_release_at.is_some() {
+            return Err(ErrorCode::RewardLocked.into());
+        }
+        
+        // Validate inputs
+        if amount == 0 || amount > contribution.reward_amount {
+            return Err(ErrorCode::InvalidClawBackAmount.into());
+        }
+        
+        if signer != contributor_token_account.owner
+            && contributor_token_account.delegate != COption::Some(signer) {
+            return Err(ErrorCode::UnauthorizedAccess.into());
+        }
+        
+        // Token transfers are halted while the program is paused
+        if ctx.accounts.mdnx_token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        if amount > contributor_token_account.amount {
+            return Err(ErrorCode::InsufficientTokenBalance.into());
+        }
+        
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: contributor_token_account.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.contributor_signer.to_account_info(),
+            },
+        );
+        
+        token::transfer(transfer_ctx, amount)?;
+        
+        contribution.reward_amount -= amount;
+        contribution.updated_at = current_timestamp;
+        
+        // Reward totals are kept in MDNX only
+        if contribution.reward_mint == Some(ctx.accounts.mdnx_token.mint) {
+            profile.total_rewards = profile.total_rewards.saturating_sub(amount);
+            model.total_rewards_distributed = model.total_rewards_distributed.saturating_sub(amount);
+        }
+        
+        msg!("Clawed back {} of the reward for contribution {}", amount, contribution.key());
+        Ok(())
+    }
+    
+    /// Create the program-owned escrow for rewards paid in a mint (permissionless)
+    pub fn init_reward_escrow(
+        ctx: Context<InitRewardEscrow>,
+    ) -> Result<()> {
+        msg!("Reward escrow initialized for mint {}", ctx.accounts.mint.key());
+        Ok(())
+    }
+    
+    /// Create the pool paying a model's auto-approval rewards (model owner only);
+    /// it is funded with plain token transfers
+    pub fn init_model_reward_pool(
+        ctx: Context<InitModelRewardPool>,
+    ) -> Result<()> {
+        msg!("Reward pool initialized for model {}", ctx.accounts.model_registry.key());
+        Ok(())
+    }
+    
+    /// Withdraw unused tokens from a model's reward pool (model owner only)
+    pub fn withdraw_model_reward_pool(
+        ctx: Context<WithdrawModelRewardPool>,
+        amount: u64,
+    ) -> Result<()> {
+        // Token transfers are halted while the program is paused
+        if ctx.accounts.mdnx_token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        if amount == 0 || amount > ctx.accounts.reward_pool.amount {
+            return Err(ErrorCode::InsufficientTokenBalance.into());
+        }
+        
+        transfer_from_reward_pool(
+            &ctx.accounts.token_program,
+            &ctx.accounts.reward_pool,
+            ctx.accounts.model_registry.key(),
+            ctx.accounts.destination.to_account_info(),
+            *ctx.bumps.get("reward_pool").unwrap(),
+            amount,
+        )?;
+        
+        msg!("Withdrew {} from the reward pool of model {}", amount, ctx.accounts.model_registry.key());
+        Ok(())
+    }
+    
+    /// Withdraw an escrowed reward once its lock has elapsed (contributor only)
+    pub fn claim_reward(
+        ctx: Context<ClaimReward>,
+    ) -> Result<()> {
+        let contribution = &mut ctx.accounts.contribution;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Disputed rewards stay in escrow until the dispute is resolved or lapses
+        contribution.lapse_expired_dispute(current_timestamp);
+        if contribution.status != ContributionStatus::Approved {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        let release_at = contribution.escrow_release_at.ok_or(ErrorCode::NoEscrowedReward)?;
+        if current_timestamp < release_at {
+            return Err(ErrorCode::RewardLocked.into());
+        }
+        
+        // Token transfers are halted while the program is paused
+        if ctx.accounts.mdnx_token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        let amount = contribution.escrow_amount;
+        release_escrowed_reward(
+            &ctx.accounts.token_program,
+            &ctx.accounts.reward_escrow,
+            ctx.accounts.contributor_token_account.to_account_info(),
+            *ctx.bumps.get("reward_escrow").unwrap(),
+            amount,
+        )?;
+        
+        contribution.escrow_amount = 0;
+        contribution.escrow_release_at = None;
+        contribution.updated_at = current_timestamp;
+        
+        msg!("Claimed {} escrowed reward tokens for contribution {}", amount, contribution.key());
+        Ok(())
+    }
+    
+    /// Refund the escrowed reward of a disputed contribution to the account
+    /// that funded it (model owner only)
+    pub fn cancel_reward_escrow(
+        ctx: Context<CancelRewardEscrow>,
+    ) -> Result<()> {
+        let contribution = &mut ctx.accounts.contribution;
+        let model = &mut ctx.accounts.model_registry;
+        let profile = &mut ctx.accounts.contributor_profile;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only a disputed (or since reversed) contribution loses its escrow
+        if contribution.status != ContributionStatus::Disputed
+            && contribution.status != ContributionStatus::Reversed {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        // A lapsed dispute no longer holds the reward back
+        if contribution.status == ContributionStatus::Disputed
+            && !contribution.has_open_dispute(current_timestamp) {
+            return Err(ErrorCode::DisputeLapsed.into());
+        }
+        
+        if contribution.escrow_release_at.is_none() {
+            return Err(ErrorCode::NoEscrowedReward.into());
+        }
+        
+        // Token transfers are halted while the program is paused
+        if ctx.accounts.mdnx_token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        let amount = contribution.escrow_amount;
+        release_escrowed_reward(
+            &ctx.accounts.token_program,
+            &ctx.accounts.reward_escrow,
+            ctx.accounts.treasury.to_account_info(),
+            *ctx.bumps.get("reward_escrow").unwrap(),
+            amount,
+        )?;
+        
+        contribution.reward_amount = contribution.reward_amount.saturating_sub(amount);
+        contribution.escrow_amount = 0;
+        contribution.escrow_release_at = None;
+        contribution.updated_at = current_timestamp;
+        
+        // Reward totals are kept in MDNX only
+        if contribution.reward_mint == Some(ctx.accounts.mdnx_token.mint) {
+            profile.total_rewards = profile.total_rewards.saturating_sub(amount);
+            model.total_rewards_distributed = model.total_rewards_distributed.saturating_sub(amount);
+        }
+        
+        msg!("Refunded {} escrowed reward tokens for contribution {}", amount, contribution.key());
         Ok(())
     }
 }
 
+/// Context for adding a reviewer
+#[derive(Accounts)]
+pub struct AddReviewer<'info> {
+    /// The reviewer registry PDA for the MDNX token
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ReviewerRegistry::LEN,
+        seeds = [REVIEWER_REGISTRY_SEED, mdnx_token.key().as_ref()],
+        bump
+    )]
+    pub reviewer_registry: Account<'info, ReviewerRegistry>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Authority (payer)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for removing a reviewer
+#[derive(Accounts)]
+pub struct RemoveReviewer<'info> {
+    /// The reviewer registry PDA for the MDNX token
+    #[account(
+        mut,
+        seeds = [REVIEWER_REGISTRY_SEED, mdnx_token.key().as_ref()],
+        bump = reviewer_registry.bump
+    )]
+    pub reviewer_registry: Account<'info, ReviewerRegistry>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Authority
+    pub authority: Signer<'info>,
+}
+
 /// Context for reviewing a contribution
 #[derive(Accounts)]
 pub struct ReviewContribution<'info> {
@@ -241,23 +2007,513 @@ pub struct ReviewContribution<'info> {
     #[account(mut)]
     pub contribution: Account<'info, Contribution>,
     
-    /// Reviewer with permission to review
+    /// Registry of approved reviewers for the contribution's token
+    #[account(
+        seeds = [REVIEWER_REGISTRY_SEED, contribution.mdnx_token.as_ref()],
+        bump = reviewer_registry.bump
+    )]
+    pub reviewer_registry: Account<'info, ReviewerRegistry>,
+    
+    /// Reviewer (must be in the reviewer registry)
+    #[account(
+        constraint = reviewer_registry.is_reviewer(&reviewer.key()) @ ErrorCode::UnauthorizedAccess
+    )]
     pub reviewer: Signer<'info>,
 }
 
+/// Context for withdrawing a contribution
+#[derive(Accounts)]
+pub struct WithdrawContribution<'info> {
+    /// Contribution to withdraw (closed, rent refunded to the contributor)
+    #[account(
+        mut,
+        close = contributor,
+        has_one = contributor @ ErrorCode::UnauthorizedAccess,
+        constraint = contribution.model == model_registry.key() @ ErrorCode::ModelMismatch,
+        constraint = contribution.mdnx_token == mdnx_token.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// The model referenced by the contribution
+    #[account(mut)]
+    pub model_registry: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Vault holding contribution bonds
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, mdnx_token.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    /// Contributor's token account (destination for the bond refund)
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = contributor_token_account.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+    
+    /// Original contributor
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for reassigning a pending contribution to another model
+#[derive(Accounts)]
+pub struct ReassignContribution<'info> {
+    /// Pending contribution to move
+    #[account(
+        mut,
+        constraint = contribution.model == old_model.key() @ ErrorCode::ModelMismatch
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// The model the contribution was recorded against
+    #[account(mut)]
+    pub old_model: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The corrected model
+    #[account(
+        mut,
+        constraint = new_model.key() != old_model.key() @ ErrorCode::ModelMismatch
+    )]
+    pub new_model: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The contributor, or the original model's authority
+    pub signer: Signer<'info>,
+    
+    /// The corrected model's authority (required unless the contributor signs)
+    pub target_authority: Option<Signer<'info>>,
+}
+
 /// Context for rejecting a contribution
 #[derive(Accounts)]
 pub struct RejectContribution<'info> {
     /// Contribution to reject
+    #[account(
+        mut,
+        constraint = contribution.model == model_registry.key() @ ErrorCode::ModelMismatch,
+        constraint = contribution.mdnx_token == mdnx_token.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// The model referenced by the contribution
+    pub model_registry: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The contributor's profile
+    #[account(
+        mut,
+        seeds = [CONTRIBUTOR_PROFILE_SEED, contribution.contributor.as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+    
+    /// Registry of approved reviewers
+    #[account(
+        seeds = [REVIEWER_REGISTRY_SEED, mdnx_token.key().as_ref()],
+        bump = reviewer_registry.bump
+    )]
+    pub reviewer_registry: Account<'info, ReviewerRegistry>,
+    
+    /// Reviewer (must be in the reviewer registry)
+    #[account(
+        constraint = reviewer_registry.is_reviewer(&reviewer.key()) @ ErrorCode::UnauthorizedAccess
+    )]
+    pub reviewer: Signer<'info>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Vault holding contribution bonds
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, mdnx_token.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    /// Treasury token account (receives slashed bonds)
+    #[account(
+        mut,
+        constraint = treasury.key() == mdnx_token.treasury @ ErrorCode::InvalidTokenAccount
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Contributor's token account (receives refunded bonds)
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contribution.contributor @ ErrorCode::InvalidTokenAccount,
+        constraint = contributor_token_account.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contributor_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for disputing an approved contribution
+#[derive(Accounts)]
+pub struct DisputeContribution<'info> {
+    /// Contribution being disputed
+    #[account(
+        mut,
+        constraint = contribution.model == model_registry.key() @ ErrorCode::ModelMismatch
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// The model referenced by the contribution
+    pub model_registry: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// Registry of approved reviewers for the contribution's token (optional)
+    #[account(
+        seeds = [REVIEWER_REGISTRY_SEED, contribution.mdnx_token.as_ref()],
+        bump = reviewer_registry.bump
+    )]
+    pub reviewer_registry: Option<Account<'info, ReviewerRegistry>>,
+    
+    /// Disputer (model authority or an approved reviewer)
+    #[account(
+        constraint = disputer.key() == model_registry.authority ||
+            reviewer_registry.as_ref().map_or(false, |registry| registry.is_reviewer(&disputer.key()))
+            @ ErrorCode::UnauthorizedAccess
+    )]
+    pub disputer: Signer<'info>,
+}
+
+/// Context for resolving a contribution dispute
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    /// Disputed contribution
+    #[account(
+        mut,
+        constraint = contribution.model == model_registry.key() @ ErrorCode::ModelMismatch
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// The model referenced by the contribution
     #[account(mut)]
+    pub model_registry: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The contributor's profile
+    #[account(
+        mut,
+        seeds = [CONTRIBUTOR_PROFILE_SEED, contribution.contributor.as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+    
+    /// The MDNX token the contribution belongs to
+    #[account(constraint = mdnx_token.key() == contribution.mdnx_token @ ErrorCode::InvalidTokenAccount)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Registry of approved reviewers for the contribution's token
+    #[account(
+        seeds = [REVIEWER_REGISTRY_SEED, contribution.mdnx_token.as_ref()],
+        bump = reviewer_registry.bump
+    )]
+    pub reviewer_registry: Account<'info, ReviewerRegistry>,
+    
+    /// Resolver (model authority or an approved reviewer)
+    #[account(
+        constraint = resolver.key() == model_registry.authority ||
+            reviewer_registry.is_reviewer(&resolver.key()) @ ErrorCode::UnauthorizedAccess
+    )]
+    pub resolver: Signer<'info>,
+}
+
+/// Context for approving several contributions to one model
+#[derive(Accounts)]
+pub struct ApproveContributionsBatch<'info> {
+    /// The model referenced by every contribution
+    #[account(
+        mut,
+        constraint = model_registry.authority == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub model_registry: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The MDNX token account, tracking treasury reward payouts
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Treasury token account (source of rewards)
+    #[account(
+        mut,
+        constraint = treasury.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount,
+        constraint = treasury.key() == mdnx_token.treasury @ ErrorCode::InvalidTokenAccount
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Vault holding contribution bonds
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, mdnx_token.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    /// Authority (must be model owner, pays for new model contributor markers)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for clawing back an overpaid contribution reward
+#[derive(Accounts)]
+pub struct ClawBackReward<'info> {
+    /// Approved contribution whose reward is corrected
+    #[account(
+        mut,
+        constraint = contribution.model == model_registry.key() @ ErrorCode::ModelMismatch,
+        constraint = contribution.mdnx_token == mdnx_token.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// The model referenced by the contribution
+    #[account(
+        mut,
+        constraint = model_registry.authority == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub model_registry: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The contributor's profile
+    #[account(
+        mut,
+        seeds = [CONTRIBUTOR_PROFILE_SEED, contribution.contributor.as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+    
+    /// The MDNX token the contribution belongs to
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Account the reward was paid from, receiving the clawed-back tokens
+    #[account(
+        mut,
+        constraint = Some(treasury.mint) == contribution.reward_mint @ ErrorCode::InvalidTokenAccount,
+        constraint = Some(treasury.key()) == contribution.reward_source @ ErrorCode::InvalidTokenAccount
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Contributor's token account the reward was paid to
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contribution.contributor @ ErrorCode::InvalidTokenAccount,
+        constraint = contributor_token_account.mint == treasury.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+    
+    /// The contributor, or a delegate of their token account
+    pub contributor_signer: Signer<'info>,
+    
+    /// Authority (must be model owner)
+    pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for creating the reward escrow for a mint
+#[derive(Accounts)]
+pub struct InitRewardEscrow<'info> {
+    /// Program-owned escrow holding rewards until they are claimed
+    #[account(
+        init,
+        payer = payer,
+        seeds = [REWARD_ESCROW_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = reward_escrow
+    )]
+    pub reward_escrow: Account<'info, TokenAccount>,
+    
+    /// Reward mint
+    pub mint: Account<'info, Mint>,
+    
+    /// Payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for creating a model's reward pool
+#[derive(Accounts)]
+pub struct InitModelRewardPool<'info> {
+    /// Program-owned pool paying the model's auto-approval rewards
+    #[account(
+        init,
+        payer = authority,
+        seeds = [MODEL_REWARD_POOL_SEED, model_registry.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = reward_pool
+    )]
+    pub reward_pool: Account<'info, TokenAccount>,
+    
+    /// The model the pool pays rewards for
+    #[account(has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// MDNX mint, the only mint auto-approval rewards are paid in
+    #[account(constraint = mint.key() == mdnx_token.mint @ ErrorCode::InvalidTokenAccount)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Model authority (payer)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for withdrawing from a model's reward pool
+#[derive(Accounts)]
+pub struct WithdrawModelRewardPool<'info> {
+    /// The model's reward pool
+    #[account(
+        mut,
+        seeds = [MODEL_REWARD_POOL_SEED, model_registry.key().as_ref()],
+        bump,
+        constraint = reward_pool.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub reward_pool: Account<'info, TokenAccount>,
+    
+    /// The model the pool pays rewards for
+    #[account(has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Token account receiving the withdrawn tokens
+    #[account(
+        mut,
+        constraint = destination.mint == reward_pool.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for claiming an escrowed reward
+#[derive(Accounts)]
+pub struct ClaimReward<'info> {
+    /// Approved contribution whose reward is escrowed
+    #[account(
+        mut,
+        has_one = contributor @ ErrorCode::UnauthorizedAccess,
+        constraint = contribution.mdnx_token == mdnx_token.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// The MDNX token the contribution belongs to
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Escrow holding the reward, in the mint the reward was paid in
+    #[account(
+        mut,
+        seeds = [REWARD_ESCROW_SEED, reward_escrow.mint.as_ref()],
+        bump,
+        constraint = Some(reward_escrow.mint) == contribution.reward_mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub reward_escrow: Account<'info, TokenAccount>,
+    
+    /// Contributor's token account receiving the reward
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = contributor_token_account.mint == reward_escrow.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+    
+    /// Original contributor
+    pub contributor: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for refunding a disputed contribution's escrowed reward
+#[derive(Accounts)]
+pub struct CancelRewardEscrow<'info> {
+    /// Disputed contribution whose reward is escrowed
+    #[account(
+        mut,
+        constraint = contribution.model == model_registry.key() @ ErrorCode::ModelMismatch,
+        constraint = contribution.mdnx_token == mdnx_token.key() @ ErrorCode::InvalidTokenAccount
+    )]
     pub contribution: Account<'info, Contribution>,
     
-    /// Model account referenced by the contribution
-    pub model: Account<'info, crate::model_registry::ModelRegistry>,
+    /// The model referenced by the contribution
+    #[account(
+        mut,
+        constraint = model_registry.authority == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub model_registry: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The contributor's profile
+    #[account(
+        mut,
+        seeds = [CONTRIBUTOR_PROFILE_SEED, contribution.contributor.as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+    
+    /// The MDNX token the contribution belongs to
+    pub mdnx_token: Account<'info, MdnxToken>,
     
-    /// Authority (must be model owner or MDNX authority)
+    /// Escrow holding the reward, in the mint the reward was paid in
     #[account(
-        constraint = model.authority == authority.key() @ ErrorCode::Unauthorized
+        mut,
+        seeds = [REWARD_ESCROW_SEED, reward_escrow.mint.as_ref()],
+        bump,
+        constraint = Some(reward_escrow.mint) == contribution.reward_mint @ ErrorCode::InvalidTokenAccount
     )]
+    pub reward_escrow: Account<'info, TokenAccount>,
+    
+    /// Account that funded the escrow, receiving the refund
+    #[account(
+        mut,
+        constraint = treasury.mint == reward_escrow.mint @ ErrorCode::InvalidTokenAccount,
+        constraint = Some(treasury.key()) == contribution.reward_source @ ErrorCode::InvalidTokenAccount
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Authority (must be model owner)
     pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
 } 
\ No newline at end of file
@@ -32,7 +32,7 @@ pub enum ErrorCode {
     #[msg("Model mismatch")]
     ModelMismatch,
     
-    #[msg("Invalid data hash")]
+    #[msg("Data hash must be a SHA-256 digest of 64 lowercase hex characters")]
     InvalidDataHash,
     
     #[msg("Data already verified")]
@@ -58,4 +58,262 @@ pub enum ErrorCode {
     
     #[msg("Authority transfer expired")]
     AuthorityTransferExpired,
+    
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    
+    #[msg("Mint would exceed the maximum token supply")]
+    SupplyCapExceeded,
+    
+    #[msg("Invalid mint cooldown")]
+    InvalidMintCooldown,
+    
+    #[msg("Invalid multisig configuration")]
+    InvalidMultisigConfig,
+    
+    #[msg("Program is paused")]
+    ProgramPaused,
+    
+    #[msg("Signer is not an authorized minter")]
+    NotAuthorizedMinter,
+    
+    #[msg("Invalid minter list update")]
+    InvalidMinterList,
+    
+    #[msg("Metadata account or program not provided")]
+    MissingMetadataAccounts,
+    
+    #[msg("Invalid token URI")]
+    InvalidTokenUri,
+    
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    
+    #[msg("Vesting cliff not reached")]
+    VestingCliffNotReached,
+    
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    
+    #[msg("Invalid stake amount or lock duration")]
+    InvalidStakeAmount,
+    
+    #[msg("Stake is still locked")]
+    StakeLocked,
+    
+    #[msg("Invalid authority transfer window")]
+    InvalidTransferWindow,
+    
+    #[msg("Proposed authority is already the current authority")]
+    SameAuthorityProposed,
+    
+    #[msg("Invalid proposed authority")]
+    InvalidProposedAuthority,
+    
+    #[msg("Invalid batch size")]
+    InvalidBatchSize,
+    
+    #[msg("Invalid model name")]
+    InvalidModelName,
+    
+    #[msg("Model is archived")]
+    ModelArchived,
+    
+    #[msg("Model tag is empty or too long")]
+    InvalidModelTag,
+    
+    #[msg("Model already has this tag")]
+    DuplicateModelTag,
+    
+    #[msg("Model has reached the maximum number of tags")]
+    TooManyModelTags,
+    
+    #[msg("Model tag not found")]
+    ModelTagNotFound,
+    
+    #[msg("Derived model lineage is too deep")]
+    LineageTooDeep,
+    
+    #[msg("Derived model hash duplicates its parent's hash")]
+    DuplicateModelHash,
+    
+    #[msg("Storage URI must be an ipfs:// or ar:// location")]
+    InvalidStorageUri,
+    
+    #[msg("Invalid contribution status transition")]
+    InvalidStatusTransition,
+    
+    #[msg("Reviewer list is full, or the reviewer is already present or missing")]
+    InvalidReviewerList,
+    
+    #[msg("Co-contributor reward shares are invalid")]
+    InvalidRewardSplit,
+    
+    #[msg("Base reward cannot exceed the maximum reward")]
+    InvalidRewardPolicy,
+    
+    #[msg("Quorum must be between 1 and the maximum number of consensus votes")]
+    InvalidQuorum,
+    
+    #[msg("Verifier has already voted on this consensus")]
+    DuplicateConsensusVote,
+    
+    #[msg("Consensus has not reached its quorum")]
+    QuorumNotReached,
+    
+    #[msg("Consensus verification is already finalized")]
+    ConsensusAlreadyFinalized,
+    
+    #[msg("Verifier is not a registered expert")]
+    NotRegisteredExpert,
+    
+    #[msg("Expert list is full, or the expert is already present or missing")]
+    InvalidExpertList,
+    
+    #[msg("Expert specialty must be non-empty and at most 32 characters")]
+    InvalidExpertSpecialty,
+    
+    #[msg("Verification fee accounts are required when the model charges a fee")]
+    MissingVerificationFeeAccounts,
+    
+    #[msg("Verification validity must end in the future")]
+    InvalidValidityPeriod,
+    
+    #[msg("Verification has expired or been revoked")]
+    VerificationExpired,
+    
+    #[msg("A valid verification linked to the contribution is required")]
+    MissingVerification,
+    
+    #[msg("Field exceeds its maximum length")]
+    FieldTooLong,
+    
+    #[msg("Rating must be between 1 and 5")]
+    InvalidRating,
+    
+    #[msg("Signer is not an authorized verifier for this model")]
+    NotAuthorizedVerifier,
+    
+    #[msg("Verifier list is too long or contains duplicates")]
+    InvalidVerifierList,
+    
+    #[msg("Adjusted approval exceeds the claimed improvement or its full reward")]
+    InvalidAdjustment,
+    
+    #[msg("Usage quota for the current period is exhausted")]
+    UsageQuotaExceeded,
+    
+    #[msg("A usage quota needs a positive period")]
+    InvalidUsageQuota,
+    
+    #[msg("Mint freeze authority does not match the token authority")]
+    FreezeAuthorityMismatch,
+    
+    #[msg("Benchmark name must be non-empty and at most 32 characters")]
+    InvalidBenchmarkName,
+    
+    #[msg("Derivation is not permitted by the parent model's license")]
+    LicenseViolation,
+    
+    #[msg("Parent royalty exceeds the maximum share")]
+    InvalidRoyalty,
+    
+    #[msg("Parent model and fee account are required to pay its royalty")]
+    MissingRoyaltyAccounts,
+    
+    #[msg("Authority transfer cannot be accepted before its minimum delay")]
+    TransferDelayNotElapsed,
+    
+    #[msg("Confidence half-life cannot be negative")]
+    InvalidHalfLife,
+    
+    #[msg("Model type is not in the category registry")]
+    UnknownModelType,
+    
+    #[msg("Category list is full, or the category is invalid, already present or missing")]
+    InvalidCategoryList,
+    
+    #[msg("Confidence score is below the verification method's minimum")]
+    ConfidenceBelowThreshold,
+    
+    #[msg("Method policy list is full, or the method or minimum confidence is invalid")]
+    InvalidMethodPolicy,
+    
+    #[msg("Contribution cooldown cannot be negative")]
+    InvalidContributionCooldown,
+    
+    #[msg("Performance metrics must be a non-empty JSON object within the length limit")]
+    InvalidMetricsFormat,
+    
+    #[msg("Reward is below the contributor's minimum")]
+    RewardBelowMinimum,
+    
+    #[msg("Challenge period cannot be negative")]
+    InvalidChallengePeriod,
+    
+    #[msg("Verification has been challenged")]
+    VerificationChallenged,
+    
+    #[msg("Verification challenge period has not elapsed")]
+    ChallengePeriodActive,
+    
+    #[msg("Verification challenge period has elapsed")]
+    ChallengePeriodElapsed,
+    
+    #[msg("Rewards paid in a partner token cannot be clawed back")]
+    RewardNotClawable,
+    
+    #[msg("Claw-back amount must be positive and no more than the reward paid")]
+    InvalidClawBackAmount,
+    
+    #[msg("Token decimals cannot exceed 9")]
+    InvalidTokenDecimals,
+    
+    #[msg("Model accuracy is below the minimum for its type")]
+    AccuracyBelowMinimum,
+    
+    #[msg("A model cannot be merged into itself")]
+    ModelMergedIntoItself,
+    
+    #[msg("Escrow period cannot be negative")]
+    InvalidEscrowPeriod,
+    
+    #[msg("Reward escrow account required when the model escrows rewards")]
+    MissingRewardEscrow,
+    
+    #[msg("Contribution has no reward in escrow")]
+    NoEscrowedReward,
+    
+    #[msg("Reward is still held in escrow")]
+    RewardLocked,
+    
+    #[msg("Confidence bounds must satisfy min <= max <= 10000")]
+    InvalidConfidenceBounds,
+    
+    #[msg("Confidence score is outside the bounds configured for this verification type")]
+    ConfidenceOutOfTypeBounds,
+    
+    #[msg("Auto-approve threshold must be at most 10000 bps, with no reward when disabled")]
+    InvalidAutoApprovePolicy,
+    
+    #[msg("Auto-approval requires the model contributor marker account")]
+    MissingAutoApprovalAccounts,
+    
+    #[msg("Reward exceeds the treasury's per-reward or per-period payout limit")]
+    TreasuryPayoutLimitExceeded,
+    
+    #[msg("Treasury reward limits must all be zero, or a positive per-reward cap within a budget over a positive period")]
+    InvalidTreasuryRewardLimits,
+    
+    #[msg("Dispute lapsed without being resolved")]
+    DisputeLapsed,
+    
+    #[msg("Stake must stay locked through the verification's challenge window")]
+    StakeLockTooShort,
+    
+    #[msg("Model name seeds the model address and cannot change")]
+    ModelNameImmutable,
+    
+    #[msg("Only models verified by the token authority can draw rewards from the program treasury")]
+    ModelNotVerified,
 } 
\ No newline at end of file
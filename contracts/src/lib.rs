@@ -1,6 +1,10 @@
+// Anchor's error type is large and instruction arguments map onto handler parameters;
+// `map_or` stays in use because the Solana toolchain predates `is_none_or`
+#![allow(clippy::result_large_err, clippy::too_many_arguments, clippy::unnecessary_map_or)]
+
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount};
-use solana_program::system_program;
+use anchor_spl::metadata::Metadata;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 // Import project modules
 pub mod errors;
@@ -8,15 +12,21 @@ pub mod token;
 pub mod model_registry;
 pub mod contribution;
 pub mod verification;
+pub mod vesting;
+pub mod staking;
+pub mod config;
 
 // Re-export key components
-pub use errors::*;
+pub use errors::ErrorCode;
 pub use token::*;
 pub use model_registry::*;
 pub use contribution::*;
 pub use verification::*;
+pub use vesting::*;
+pub use staking::*;
+pub use config::*;
 
-declare_id!("MdNxToKenxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx");
+declare_id!("MdNxToKenMASi45ub7Qe4ZE36UT5G6cU4ud8Fhhe4de");
 
 #[program]
 pub mod medinex_ai {
@@ -29,8 +39,232 @@ pub mod medinex_ai {
         symbol: String,
         uri: String,
         total_supply: u64,
+        mint_cooldown_seconds: i64,
+        authority_transfer_window: i64,
+        min_transfer_delay_seconds: i64,
+        create_metadata: bool,
+        decimals: u8,
+    ) -> Result<()> {
+        token_operations::initialize_token(
+            ctx,
+            name,
+            symbol,
+            uri,
+            total_supply,
+            mint_cooldown_seconds,
+            authority_transfer_window,
+            min_transfer_delay_seconds,
+            create_metadata,
+            decimals
+        )
+    }
+    
+    /// Initialize the global program config
+    pub fn init_config(ctx: Context<InitConfig>) -> Result<()> {
+        config_operations::init_config(ctx)
+    }
+    
+    /// Name the canonical MDNX token (config admin only)
+    pub fn set_canonical_token(ctx: Context<SetCanonicalToken>) -> Result<()> {
+        config_operations::set_canonical_token(ctx)
+    }
+    
+    /// Set the accepted confidence range for a verification type (config admin only)
+    pub fn set_confidence_bounds(
+        ctx: Context<SetConfidenceBounds>,
+        verification_type: VerificationType,
+        min_confidence: u16,
+        max_confidence: u16,
+    ) -> Result<()> {
+        config_operations::set_confidence_bounds(ctx, verification_type, min_confidence, max_confidence)
+    }
+    
+    /// Update the global program config (admin only)
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_admin: Option<Pubkey>,
+        paused: Option<bool>,
+        default_mint_cooldown: Option<i64>,
+        default_usage_fee: Option<u64>,
+        default_verification_fee: Option<u64>,
+        contribution_cooldown: Option<i64>,
+    ) -> Result<()> {
+        config_operations::update_config(
+            ctx,
+            new_admin,
+            paused,
+            default_mint_cooldown,
+            default_usage_fee,
+            default_verification_fee,
+            contribution_cooldown
+        )
+    }
+    
+    /// Propose a new token authority
+    pub fn propose_authority_transfer(
+        ctx: Context<ProposeAuthorityTransfer>,
+        new_authority: Pubkey,
+        window: Option<i64>,
+        reason: Option<String>,
+    ) -> Result<()> {
+        token_operations::propose_authority_transfer(ctx, new_authority, window, reason)
+    }
+    
+    /// Accept a pending token authority transfer
+    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+        token_operations::accept_authority_transfer(ctx)
+    }
+    
+    /// Cancel a pending token authority transfer
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        token_operations::cancel_authority_transfer(ctx)
+    }
+    
+    /// Clear an expired authority transfer proposal (callable by anyone)
+    pub fn clear_expired_proposal(ctx: Context<ClearExpiredProposal>) -> Result<()> {
+        token_operations::clear_expired_proposal(ctx)
+    }
+    
+    /// Return whether a key is the current or proposed token authority via return data
+    pub fn get_authority_status(ctx: Context<GetAuthorityStatus>, account: Pubkey) -> Result<()> {
+        token_operations::get_authority_status(ctx, account)
+    }
+    
+    /// Mint MDNX tokens
+    pub fn mint_tokens(
+        ctx: Context<MintTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        token_operations::mint_tokens(ctx, amount)
+    }
+    
+    /// Mint MDNX tokens to many recipients
+    pub fn mint_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintBatch<'info>>,
+        amounts: Vec<u64>,
     ) -> Result<()> {
-        token_operations::initialize_token(ctx, name, symbol, uri, total_supply)
+        token_operations::mint_batch(ctx, amounts)
+    }
+    
+    /// Burn MDNX tokens
+    pub fn burn_tokens(
+        ctx: Context<BurnTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        token_operations::burn_tokens(ctx, amount)
+    }
+    
+    /// Transfer MDNX out of the treasury (authority only)
+    pub fn sweep_treasury(ctx: Context<SweepTreasury>, amount: u64) -> Result<()> {
+        token_operations::sweep_treasury(ctx, amount)
+    }
+    
+    /// Limit rewards paid from the program-owned treasury (authority only)
+    pub fn set_treasury_reward_limits(
+        ctx: Context<SetTreasuryRewardLimits>,
+        max_reward_payout: u64,
+        reward_budget: u64,
+        reward_budget_period: i64,
+    ) -> Result<()> {
+        token_operations::set_treasury_reward_limits(ctx, max_reward_payout, reward_budget, reward_budget_period)
+    }
+    
+    /// Set the treasury token account used for rewards
+    pub fn set_treasury(
+        ctx: Context<SetTreasury>,
+        new_treasury: Pubkey,
+    ) -> Result<()> {
+        token_operations::set_treasury(ctx, new_treasury)
+    }
+    
+    /// Set the minimum delay between mints
+    pub fn set_mint_cooldown(
+        ctx: Context<SetMintCooldown>,
+        mint_cooldown_seconds: i64,
+    ) -> Result<()> {
+        token_operations::set_mint_cooldown(ctx, mint_cooldown_seconds)
+    }
+    
+    /// Configure the multisig for authority operations
+    pub fn set_multisig(
+        ctx: Context<SetMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        token_operations::set_multisig(ctx, signers, threshold)
+    }
+    
+    /// Update the token metadata URI
+    pub fn update_token_uri(ctx: Context<UpdateTokenUri>, new_uri: String) -> Result<()> {
+        token_operations::update_token_uri(ctx, new_uri)
+    }
+    
+    /// Pause mint, burn and transfer operations
+    pub fn pause(ctx: Context<SetPaused>) -> Result<()> {
+        token_operations::pause(ctx)
+    }
+    
+    /// Resume mint, burn and transfer operations
+    pub fn unpause(ctx: Context<SetPaused>) -> Result<()> {
+        token_operations::unpause(ctx)
+    }
+    
+    /// Freeze a holder's MDNX token account
+    pub fn freeze_token_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
+        token_operations::freeze_token_account(ctx)
+    }
+    
+    /// Thaw a frozen MDNX token account
+    pub fn thaw_token_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
+        token_operations::thaw_token_account(ctx)
+    }
+    
+    /// Transfer the mint's freeze authority
+    pub fn set_freeze_authority(ctx: Context<SetFreezeAuthority>, new_freeze_authority: Pubkey) -> Result<()> {
+        token_operations::set_freeze_authority(ctx, new_freeze_authority)
+    }
+    
+    /// Add a delegated minter
+    pub fn add_minter(ctx: Context<ManageMinters>, minter: Pubkey) -> Result<()> {
+        token_operations::add_minter(ctx, minter)
+    }
+    
+    /// Remove a delegated minter
+    pub fn remove_minter(ctx: Context<ManageMinters>, minter: Pubkey) -> Result<()> {
+        token_operations::remove_minter(ctx, minter)
+    }
+    
+    /// Create a vesting schedule
+    pub fn create_vesting(
+        ctx: Context<CreateVesting>,
+        beneficiary: Pubkey,
+        total_amount: u64,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        vesting_operations::create_vesting(
+            ctx, beneficiary, total_amount, start_ts, cliff_ts, end_ts
+        )
+    }
+    
+    /// Claim vested tokens
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        vesting_operations::claim_vested(ctx)
+    }
+    
+    /// Stake MDNX tokens
+    pub fn stake_tokens(
+        ctx: Context<StakeTokens>,
+        amount: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        staking_operations::stake_tokens(ctx, amount, lock_duration)
+    }
+    
+    /// Unstake MDNX tokens after the lock expires
+    pub fn unstake_tokens(ctx: Context<UnstakeTokens>, amount: u64) -> Result<()> {
+        staking_operations::unstake_tokens(ctx, amount)
     }
     
     /// Register a new AI model
@@ -41,11 +275,43 @@ pub mod medinex_ai {
         version: String,
         model_type: String,
         model_hash: String,
-        accuracy: f64,
+        accuracy: u16,
         performance_metrics: String,
+        storage_uri: String,
+        license: ModelLicense,
     ) -> Result<()> {
         model_operations::register_model(
-            ctx, name, description, version, model_type, model_hash, accuracy, performance_metrics
+            ctx, name, description, version, model_type, model_hash, accuracy,
+            performance_metrics, storage_uri, license
+        )
+    }
+    
+    /// Create the global model counter used for sequential model indices
+    pub fn init_model_counter(ctx: Context<InitModelCounter>) -> Result<()> {
+        model_operations::init_model_counter(ctx)
+    }
+    
+    /// Create the global leaderboard of most-used models
+    pub fn init_leaderboard(ctx: Context<InitLeaderboard>) -> Result<()> {
+        model_operations::init_leaderboard(ctx)
+    }
+    
+    /// Create a model derived from a parent model
+    pub fn create_derived_model(
+        ctx: Context<CreateDerivedModel>,
+        name: String,
+        description: String,
+        version: String,
+        model_type: String,
+        model_hash: String,
+        accuracy: u16,
+        performance_metrics: String,
+        storage_uri: String,
+        license: ModelLicense,
+    ) -> Result<()> {
+        model_operations::create_derived_model(
+            ctx, name, description, version, model_type, model_hash, accuracy,
+            performance_metrics, storage_uri, license
         )
     }
     
@@ -56,22 +322,182 @@ pub mod medinex_ai {
         description: Option<String>,
         version: Option<String>,
         model_hash: Option<String>,
-        accuracy: Option<f64>,
+        accuracy: Option<u16>,
         performance_metrics: Option<String>,
+        storage_uri: Option<String>,
     ) -> Result<()> {
         model_operations::update_model(
-            ctx, name, description, version, model_hash, accuracy, performance_metrics
+            ctx, name, description, version, model_hash, accuracy, performance_metrics, storage_uri
         )
     }
     
+    /// Propose a new model authority
+    pub fn propose_model_transfer(
+        ctx: Context<ProposeModelTransfer>,
+        new_authority: Pubkey,
+        window: Option<i64>,
+    ) -> Result<()> {
+        model_operations::propose_model_transfer(ctx, new_authority, window)
+    }
+    
+    /// Accept a pending model ownership transfer
+    pub fn accept_model_transfer(ctx: Context<AcceptModelTransfer>) -> Result<()> {
+        model_operations::accept_model_transfer(ctx)
+    }
+    
+    /// Set the lifecycle status of a model
+    pub fn set_model_status(ctx: Context<SetModelStatus>, status: ModelStatus) -> Result<()> {
+        model_operations::set_model_status(ctx, status)
+    }
+    
+    /// Archive a model, emitting its full state and optionally closing it
+    pub fn archive_model(ctx: Context<ArchiveModel>, close: bool) -> Result<()> {
+        model_operations::archive_model(ctx, close)
+    }
+    
+    /// Merge a model's statistics into another and archive it (both authorities)
+    pub fn merge_models(ctx: Context<MergeModels>) -> Result<()> {
+        model_operations::merge_models(ctx)
+    }
+    
+    /// Set the MDNX fee charged per model usage
+    pub fn set_usage_fee(ctx: Context<SetUsageFee>, usage_fee: u64) -> Result<()> {
+        model_operations::set_usage_fee(ctx, usage_fee)
+    }
+    
+    /// Set the share of a derived model's usage fees owed to its parent's owner
+    pub fn set_parent_royalty(ctx: Context<SetParentRoyalty>, royalty_bps: u16) -> Result<()> {
+        model_operations::set_parent_royalty(ctx, royalty_bps)
+    }
+    
+    /// Set the half-life over which a model's verification confidence decays
+    pub fn set_confidence_half_life(ctx: Context<SetConfidenceHalfLife>, half_life_seconds: i64) -> Result<()> {
+        model_operations::set_confidence_half_life(ctx, half_life_seconds)
+    }
+    
+    /// Set how long new verifications of a model can be challenged before they count
+    pub fn set_verification_challenge_period(
+        ctx: Context<SetVerificationChallengePeriod>,
+        challenge_period: i64,
+    ) -> Result<()> {
+        model_operations::set_verification_challenge_period(ctx, challenge_period)
+    }
+    
+    /// Set how long approved rewards are escrowed before the contributor can claim them
+    pub fn set_reward_escrow_period(ctx: Context<SetRewardEscrowPeriod>, escrow_period: i64) -> Result<()> {
+        model_operations::set_reward_escrow_period(ctx, escrow_period)
+    }
+    
+    /// Auto-approve small contributions to a model, optionally paying a fixed reward from the model's reward pool
+    pub fn set_auto_approve_policy(ctx: Context<SetAutoApprovePolicy>, threshold: Option<u16>, reward: u64) -> Result<()> {
+        model_operations::set_auto_approve_policy(ctx, threshold, reward)
+    }
+    
+    /// Cap how many times a model can be used per period
+    pub fn set_usage_quota(ctx: Context<SetUsageQuota>, usage_quota: u64, usage_period_seconds: i64) -> Result<()> {
+        model_operations::set_usage_quota(ctx, usage_quota, usage_period_seconds)
+    }
+    
+    /// Set the MDNX fee paid to verifiers of a model
+    pub fn set_verification_fee(ctx: Context<SetVerificationFee>, verification_fee: u64) -> Result<()> {
+        model_operations::set_verification_fee(ctx, verification_fee)
+    }
+    
+    /// Set the verifiers allowed to verify a model's outputs
+    pub fn set_model_verifiers(ctx: Context<SetModelVerifiers>, verifiers: Vec<Pubkey>) -> Result<()> {
+        model_operations::set_model_verifiers(ctx, verifiers)
+    }
+    
+    /// Require contributions to a model to carry a linked verification before approval
+    pub fn set_verification_requirement(ctx: Context<SetVerificationRequirement>, required: bool) -> Result<()> {
+        model_operations::set_verification_requirement(ctx, required)
+    }
+    
+    /// Rate a model from 1 to 5, once per user
+    pub fn rate_model(ctx: Context<RateModel>, score: u8) -> Result<()> {
+        model_operations::rate_model(ctx, score)
+    }
+    
+    /// Register a valid model type (config admin only)
+    pub fn add_category(ctx: Context<AddCategory>, category: String) -> Result<()> {
+        model_operations::add_category(ctx, category)
+    }
+    
+    /// Remove a registered model type (config admin only)
+    pub fn remove_category(ctx: Context<RemoveCategory>, category: String) -> Result<()> {
+        model_operations::remove_category(ctx, category)
+    }
+    
+    /// Set the minimum registration accuracy for a model type (config admin only)
+    pub fn set_category_min_accuracy(
+        ctx: Context<SetCategoryMinAccuracy>,
+        model_type: String,
+        min_accuracy: u16,
+    ) -> Result<()> {
+        model_operations::set_category_min_accuracy(ctx, model_type, min_accuracy)
+    }
+    
+    /// Attach a named benchmark result to a model
+    pub fn submit_benchmark(
+        ctx: Context<SubmitBenchmark>,
+        benchmark_name: String,
+        dataset_hash: String,
+        score: u16,
+    ) -> Result<()> {
+        model_operations::submit_benchmark(ctx, benchmark_name, dataset_hash, score)
+    }
+    
+    /// Record usage of a model, paying its usage fee
+    pub fn record_usage(ctx: Context<RecordModelUsage>, confidence_score: u16) -> Result<()> {
+        model_operations::record_usage(ctx, confidence_score)
+    }
+    
+    /// Set the reward policy for contributions to a model
+    pub fn set_reward_policy(
+        ctx: Context<SetRewardPolicy>,
+        base_reward: u64,
+        accuracy_multiplier: u64,
+        max_reward: u64,
+    ) -> Result<()> {
+        model_operations::set_reward_policy(ctx, base_reward, accuracy_multiplier, max_reward)
+    }
+    
+    /// Add a discovery tag to a model
+    pub fn add_model_tag(ctx: Context<ManageModelTags>, tag: String) -> Result<()> {
+        model_operations::add_model_tag(ctx, tag)
+    }
+    
+    /// Remove a discovery tag from a model
+    pub fn remove_model_tag(ctx: Context<ManageModelTags>, tag: String) -> Result<()> {
+        model_operations::remove_model_tag(ctx, tag)
+    }
+    
+    /// Mark a model as verified (token authority only)
+    pub fn verify_model(ctx: Context<VerifyModel>) -> Result<()> {
+        model_operations::verify_model(ctx)
+    }
+    
+    /// Clear a model's verified flag (token authority only)
+    pub fn unverify_model(ctx: Context<UnverifyModel>) -> Result<()> {
+        model_operations::unverify_model(ctx)
+    }
+    
+    /// Return a model's statistics snapshot via return data
+    pub fn get_model_stats(ctx: Context<GetModelStats>) -> Result<()> {
+        model_operations::get_model_stats(ctx)
+    }
+    
     /// Record a model contribution
     pub fn record_contribution(
         ctx: Context<RecordContribution>,
         description: String,
         contribution_type: String,
-        accuracy_improvement: f64,
+        accuracy_improvement: u16,
         performance_improvement: String,
         contribution_hash: String,
+        co_contributors: Vec<RewardShare>,
+        bond_amount: u64,
+        min_reward: Option<u64>,
     ) -> Result<()> {
         contribution_operations::record_contribution(
             ctx,
@@ -79,16 +505,150 @@ pub mod medinex_ai {
             contribution_type,
             accuracy_improvement,
             performance_improvement,
+            contribution_hash,
+            co_contributors,
+            bond_amount,
+            min_reward
+        )
+    }
+    
+    /// Register a model together with its creator's first contribution
+    pub fn register_model_with_contribution(
+        ctx: Context<RegisterModelWithContribution>,
+        name: String,
+        description: String,
+        version: String,
+        model_type: String,
+        model_hash: String,
+        accuracy: u16,
+        performance_metrics: String,
+        storage_uri: String,
+        license: ModelLicense,
+        contribution_description: String,
+        contribution_type: String,
+        accuracy_improvement: u16,
+        performance_improvement: String,
+        contribution_hash: String,
+    ) -> Result<()> {
+        contribution_operations::register_model_with_contribution(
+            ctx,
+            name,
+            description,
+            version,
+            model_type,
+            model_hash,
+            accuracy,
+            performance_metrics,
+            storage_uri,
+            license,
+            contribution_description,
+            contribution_type,
+            accuracy_improvement,
+            performance_improvement,
             contribution_hash
         )
     }
     
-    /// Approve a contribution and distribute rewards
-    pub fn approve_contribution(
-        ctx: Context<ApproveContribution>,
+    /// Add an approved contribution reviewer
+    pub fn add_reviewer(ctx: Context<AddReviewer>, reviewer: Pubkey) -> Result<()> {
+        contribution_operations::add_reviewer(ctx, reviewer)
+    }
+    
+    /// Remove an approved contribution reviewer
+    pub fn remove_reviewer(ctx: Context<RemoveReviewer>, reviewer: Pubkey) -> Result<()> {
+        contribution_operations::remove_reviewer(ctx, reviewer)
+    }
+    
+    /// Move a contribution through the review workflow
+    pub fn review_contribution(
+        ctx: Context<ReviewContribution>,
+        status: ContributionStatus,
+        notes: String,
+    ) -> Result<()> {
+        contribution_operations::review_contribution(ctx, status, notes)
+    }
+    
+    /// Approve a contribution and distribute rewards (in MDNX or a partner token)
+    pub fn approve_contribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveContribution<'info>>,
+    ) -> Result<()> {
+        contribution_operations::approve_contribution(ctx)
+    }
+    
+    /// Approve a contribution at a reduced accuracy improvement and reward
+    pub fn approve_contribution_partial<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveContribution<'info>>,
+        accuracy_improvement: u16,
         reward_amount: u64,
     ) -> Result<()> {
-        contribution_operations::approve_contribution(ctx, reward_amount)
+        contribution_operations::approve_contribution_partial(ctx, accuracy_improvement, reward_amount)
+    }
+    
+    /// Approve several contributions to one model in a single transaction
+    pub fn approve_contributions_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveContributionsBatch<'info>>,
+        rewards: Vec<u64>,
+    ) -> Result<()> {
+        contribution_operations::approve_contributions_batch(ctx, rewards)
+    }
+    
+    /// Withdraw a pending or in-review contribution
+    pub fn withdraw_contribution(ctx: Context<WithdrawContribution>) -> Result<()> {
+        contribution_operations::withdraw_contribution(ctx)
+    }
+    
+    /// Move a pending contribution to the correct model
+    pub fn reassign_contribution(ctx: Context<ReassignContribution>) -> Result<()> {
+        contribution_operations::reassign_contribution(ctx)
+    }
+    
+    /// Reject a contribution, slashing its bond or refunding it to the contributor
+    pub fn reject_contribution(
+        ctx: Context<RejectContribution>,
+        rejection_reason: String,
+        refund_bond: bool,
+    ) -> Result<()> {
+        contribution_operations::reject_contribution(ctx, rejection_reason, refund_bond)
+    }
+    
+    /// Dispute an approved contribution (model owner or approved reviewer only)
+    pub fn dispute_contribution(ctx: Context<DisputeContribution>, reason: String) -> Result<()> {
+        contribution_operations::dispute_contribution(ctx, reason)
+    }
+    
+    /// Resolve a contribution dispute, optionally reversing it and clawing back the reward
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, reverse: bool, claw_back: bool) -> Result<()> {
+        contribution_operations::resolve_dispute(ctx, reverse, claw_back)
+    }
+    
+    /// Claw back part of an overpaid contribution reward (model owner, co-signed by the contributor)
+    pub fn claw_back_reward(ctx: Context<ClawBackReward>, amount: u64) -> Result<()> {
+        contribution_operations::claw_back_reward(ctx, amount)
+    }
+    
+    /// Create the program-owned escrow for rewards paid in a mint
+    pub fn init_reward_escrow(ctx: Context<InitRewardEscrow>) -> Result<()> {
+        contribution_operations::init_reward_escrow(ctx)
+    }
+    
+    /// Create the pool paying a model's auto-approval rewards (model owner only)
+    pub fn init_model_reward_pool(ctx: Context<InitModelRewardPool>) -> Result<()> {
+        contribution_operations::init_model_reward_pool(ctx)
+    }
+    
+    /// Withdraw unused tokens from a model's reward pool (model owner only)
+    pub fn withdraw_model_reward_pool(ctx: Context<WithdrawModelRewardPool>, amount: u64) -> Result<()> {
+        contribution_operations::withdraw_model_reward_pool(ctx, amount)
+    }
+    
+    /// Claim an escrowed contribution reward after its lock (contributor only)
+    pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
+        contribution_operations::claim_reward(ctx)
+    }
+    
+    /// Refund a disputed contribution's escrowed reward to the account that funded it (model owner only)
+    pub fn cancel_reward_escrow(ctx: Context<CancelRewardEscrow>) -> Result<()> {
+        contribution_operations::cancel_reward_escrow(ctx)
     }
     
     /// Verify medical data
@@ -96,9 +656,10 @@ pub mod medinex_ai {
         ctx: Context<VerifyData>,
         data_hash: String,
         verification_method: String,
-        confidence_score: f64,
+        confidence_score: u16,
         metadata: String,
         result_details: String,
+        valid_until: Option<i64>,
     ) -> Result<()> {
         verification_operations::verify_data(
             ctx,
@@ -106,7 +667,8 @@ pub mod medinex_ai {
             verification_method,
             confidence_score,
             metadata,
-            result_details
+            result_details,
+            valid_until
         )
     }
     
@@ -115,9 +677,10 @@ pub mod medinex_ai {
         ctx: Context<VerifyAnalysis>,
         data_hash: String,
         verification_method: String,
-        confidence_score: f64,
+        confidence_score: u16,
         metadata: String,
         result_details: String,
+        valid_until: Option<i64>,
     ) -> Result<()> {
         verification_operations::verify_analysis(
             ctx,
@@ -125,18 +688,20 @@ pub mod medinex_ai {
             verification_method,
             confidence_score,
             metadata,
-            result_details
+            result_details,
+            valid_until
         )
     }
     
     /// Verify model output
     pub fn verify_model_output(
-        ctx: Context<verification::VerifyModelOutput>,
+        ctx: Context<VerifyModelOutput>,
         data_hash: String,
         verification_method: String,
-        confidence_score: f64,
+        confidence_score: u16,
         metadata: String,
         result_details: String,
+        valid_until: Option<i64>,
     ) -> Result<()> {
         verification_operations::verify_model_output(
             ctx,
@@ -144,18 +709,20 @@ pub mod medinex_ai {
             verification_method,
             confidence_score,
             metadata,
-            result_details
+            result_details,
+            valid_until
         )
     }
     
     /// Expert verification
     pub fn expert_verification(
-        ctx: Context<verification::ExpertVerification>,
+        ctx: Context<ExpertVerification>,
         data_hash: String,
         verification_method: String,
-        confidence_score: f64,
+        confidence_score: u16,
         metadata: String,
         result_details: String,
+        valid_until: Option<i64>,
     ) -> Result<()> {
         verification_operations::expert_verification(
             ctx,
@@ -163,23 +730,123 @@ pub mod medinex_ai {
             verification_method,
             confidence_score,
             metadata,
-            result_details
+            result_details,
+            valid_until
         )
     }
+    
+    /// Register a credentialed expert
+    pub fn register_expert(
+        ctx: Context<RegisterExpert>,
+        expert: Pubkey,
+        specialty: String,
+    ) -> Result<()> {
+        verification_operations::register_expert(ctx, expert, specialty)
+    }
+    
+    /// Require a minimum confidence for a verification method (config admin only)
+    pub fn set_method_policy(ctx: Context<SetMethodPolicy>, method: String, min_confidence: u16) -> Result<()> {
+        verification_operations::set_method_policy(ctx, method, min_confidence)
+    }
+    
+    /// Drop a verification method's minimum confidence (config admin only)
+    pub fn remove_method_policy(ctx: Context<RemoveMethodPolicy>, method: String) -> Result<()> {
+        verification_operations::remove_method_policy(ctx, method)
+    }
+    
+    /// Remove a credentialed expert
+    pub fn remove_expert(ctx: Context<RemoveExpert>, expert: Pubkey) -> Result<()> {
+        verification_operations::remove_expert(ctx, expert)
+    }
+    
+    /// Open a multi-verifier consensus verification
+    pub fn open_consensus(
+        ctx: Context<OpenConsensus>,
+        data_hash: String,
+        quorum: u8,
+    ) -> Result<()> {
+        verification_operations::open_consensus(ctx, data_hash, quorum)
+    }
+    
+    /// Submit a vote to a consensus verification
+    pub fn submit_consensus_vote(
+        ctx: Context<SubmitConsensusVote>,
+        confidence_score: u16,
+    ) -> Result<()> {
+        verification_operations::submit_consensus_vote(ctx, confidence_score)
+    }
+    
+    /// Finalize a consensus verification once quorum is reached
+    pub fn finalize_consensus(ctx: Context<FinalizeConsensus>) -> Result<()> {
+        verification_operations::finalize_consensus(ctx)
+    }
+    
+    /// Revoke a verification (original verifier only)
+    pub fn revoke_verification(ctx: Context<RevokeVerification>) -> Result<()> {
+        verification_operations::revoke_verification(ctx)
+    }
+    
+    /// Challenge a verification during its challenge period
+    pub fn challenge_verification(ctx: Context<ChallengeVerification>) -> Result<()> {
+        verification_operations::challenge_verification(ctx)
+    }
+    
+    /// Uphold or dismiss a challenge to a verification (config admin only)
+    pub fn resolve_challenge(ctx: Context<ResolveChallenge>, uphold: bool) -> Result<()> {
+        verification_operations::resolve_challenge(ctx, uphold)
+    }
+    
+    /// Count an unchallenged verification towards its model after the challenge period
+    pub fn finalize_verification(ctx: Context<FinalizeVerification>) -> Result<()> {
+        verification_operations::finalize_verification(ctx)
+    }
+    
+    /// Return a data hash's verification count and mean confidence via return data
+    pub fn get_verification_aggregate(ctx: Context<GetVerificationAggregate>) -> Result<()> {
+        verification_operations::get_verification_aggregate(ctx)
+    }
 }
 
 /// Context for initializing the MDNX token
 #[derive(Accounts)]
-#[instruction(name: String, symbol: String, uri: String, total_supply: u64)]
+#[instruction(
+    name: String,
+    symbol: String,
+    uri: String,
+    total_supply: u64,
+    mint_cooldown_seconds: i64,
+    authority_transfer_window: i64,
+    min_transfer_delay_seconds: i64,
+    create_metadata: bool,
+    decimals: u8
+)]
 pub struct InitializeToken<'info> {
-    /// The MDNX token account
-    #[account(init, payer = authority, space = 8 + MdnxToken::LEN)]
+    /// The MDNX token account, a singleton PDA per mint (the mint's `init`
+    /// already rules out initializing a token twice)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MdnxToken::LEN,
+        seeds = [MDNX_TOKEN_SEED, mint.key().as_ref()],
+        bump
+    )]
     pub mdnx_token: Account<'info, MdnxToken>,
     
     /// The token mint
-    #[account(init, payer = authority, mint::decimals = 9, mint::authority = authority)]
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = decimals,
+        mint::authority = mint_authority,
+        mint::freeze_authority = authority
+    )]
     pub mint: Account<'info, Mint>,
     
+    /// Mint authority PDA, shared by the authority and delegated minters
+    /// CHECK: PDA only used as the mint authority
+    #[account(seeds = [MINT_AUTHORITY_SEED, mint.key().as_ref()], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    
     /// Authority account (payer)
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -193,12 +860,35 @@ pub struct InitializeToken<'info> {
     )]
     pub authority_token_account: Account<'info, TokenAccount>,
     
+    /// Program-owned treasury paying out rewards, signed for by its own PDA
+    #[account(
+        init,
+        payer = authority,
+        seeds = [TREASURY_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = treasury
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
     /// Token program
     pub token_program: Program<'info, Token>,
     
     /// Associated token program
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     
+    /// Metaplex metadata account for the mint (required when creating metadata)
+    /// CHECK: PDA validated by the token metadata program
+    #[account(mut)]
+    pub metadata: Option<UncheckedAccount<'info>>,
+    
+    /// Metaplex token metadata program (required when creating metadata)
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+    
+    /// Global config supplying defaults (optional)
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Option<Account<'info, Config>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
@@ -216,14 +906,43 @@ pub struct InitializeToken<'info> {
     model_hash: String
 )]
 pub struct RegisterModel<'info> {
-    /// Initialize a new model registry account
-    #[account(init, payer = authority, space = ModelRegistry::LEN)]
+    /// Model registry PDA keyed by authority and name
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ModelRegistry::LEN,
+        seeds = [MODEL_SEED, authority.key().as_ref(), name.as_bytes()],
+        bump
+    )]
     pub model_registry: Account<'info, ModelRegistry>,
     
     /// Model authority (payer)
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    /// Global model counter handing out the sequential index
+    #[account(mut, seeds = [MODEL_COUNTER_SEED], bump = model_counter.bump)]
+    pub model_counter: Account<'info, ModelRegistryCounter>,
+    
+    /// Index entry pointing at the new model
+    #[account(
+        init,
+        payer = authority,
+        space = ModelIndexEntry::LEN,
+        seeds = [MODEL_INDEX_SEED, &model_counter.model_count.to_le_bytes()],
+        bump
+    )]
+    pub model_index: Account<'info, ModelIndexEntry>,
+    
+    /// Registry of valid model types, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [CATEGORY_REGISTRY_SEED], bump)]
+    pub category_registry: UncheckedAccount<'info>,
+    
+    /// Global config supplying defaults (optional)
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Option<Account<'info, Config>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
@@ -233,13 +952,35 @@ pub struct RegisterModel<'info> {
 
 /// Context for updating an AI model
 #[derive(Accounts)]
+#[instruction(
+    name: Option<String>,
+    description: Option<String>,
+    version: Option<String>,
+    model_hash: Option<String>,
+    accuracy: Option<u16>,
+    performance_metrics: Option<String>
+)]
 pub struct UpdateModel<'info> {
-    /// Model registry to update
-    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    /// Model registry to update, resized to fit longer text fields (oversized
+    /// values are ignored here and rejected by the handler)
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedAccess,
+        realloc = model_registry.required_space(
+            description.as_ref().filter(|d| d.len() <= MAX_DESCRIPTION_LEN),
+            performance_metrics.as_ref().filter(|m| m.len() <= MAX_PERFORMANCE_METRICS_LEN),
+        ),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
     pub model_registry: Account<'info, ModelRegistry>,
     
-    /// Model authority
+    /// Model authority (pays for any growth)
+    #[account(mut)]
     pub authority: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
 }
 
 /// Context for recording a contribution
@@ -247,7 +988,7 @@ pub struct UpdateModel<'info> {
 #[instruction(
     description: String, 
     contribution_type: String,
-    accuracy_improvement: f64,
+    accuracy_improvement: u16,
     performance_improvement: String,
     contribution_hash: String
 )]
@@ -257,13 +998,74 @@ pub struct RecordContribution<'info> {
     pub contribution: Account<'info, Contribution>,
     
     /// The model being contributed to
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = model_registry.status != ModelStatus::Archived @ ErrorCode::ModelArchived
+    )]
     pub model_registry: Account<'info, ModelRegistry>,
     
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Token mint
+    #[account(constraint = mint.key() == mdnx_token.mint @ ErrorCode::InvalidTokenAccount)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Program-owned vault holding contribution bonds
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        seeds = [BOND_VAULT_SEED, mdnx_token.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = bond_vault
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    /// The contributor's profile, created on first contribution
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = ContributorProfile::LEN,
+        seeds = [CONTRIBUTOR_PROFILE_SEED, contributor.key().as_ref()],
+        bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+    
+    /// Contributor's token account (source of the bond)
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = contributor_token_account.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+    
+    /// Marker counting the contributor once per model (required for auto-approval)
+    /// CHECK: PDA verified against the model and contributor on auto-approval
+    #[account(mut)]
+    pub model_contributor: Option<UncheckedAccount<'info>>,
+    
+    /// The model's reward pool paying the auto-approval reward (optional)
+    #[account(
+        mut,
+        seeds = [MODEL_REWARD_POOL_SEED, model_registry.key().as_ref()],
+        bump,
+        constraint = reward_pool.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub reward_pool: Option<Account<'info, TokenAccount>>,
+    
+    /// Global config supplying the contribution cooldown, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    
     /// Contributor (payer)
     #[account(mut)]
     pub contributor: Signer<'info>,
     
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
@@ -271,43 +1073,134 @@ pub struct RecordContribution<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// Context for registering a model together with its first contribution
+#[derive(Accounts)]
+pub struct RegisterModelWithContribution<'info> {
+    /// Accounts for registering the model; this instruction's arguments start
+    /// with register_model's, so its seeds resolve the same way
+    pub register: RegisterModel<'info>,
+    
+    /// Initialize a new contribution record
+    #[account(init, payer = register.authority, space = Contribution::LEN)]
+    pub contribution: Account<'info, Contribution>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// The model creator's contributor profile, created on first contribution
+    #[account(
+        init_if_needed,
+        payer = register.authority,
+        space = ContributorProfile::LEN,
+        seeds = [CONTRIBUTOR_PROFILE_SEED, register.authority.key().as_ref()],
+        bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
 /// Context for approving a contribution
 #[derive(Accounts)]
 pub struct ApproveContribution<'info> {
     /// Contribution to approve
     #[account(
         mut,
-        constraint = contribution.model == model_registry.key() @ ErrorCode::ModelMismatch
+        constraint = contribution.model == model_registry.key() @ ErrorCode::ModelMismatch,
+        constraint = contribution.mdnx_token == mdnx_token.key() @ ErrorCode::InvalidTokenAccount
     )]
     pub contribution: Account<'info, Contribution>,
     
     /// The model referenced by the contribution
     #[account(
         mut,
-        constraint = model_registry.authority == authority.key() @ ErrorCode::Unauthorized
+        constraint = model_registry.authority == authority.key() @ ErrorCode::UnauthorizedAccess
     )]
     pub model_registry: Account<'info, ModelRegistry>,
     
-    /// The MDNX token account
+    /// The MDNX token account, tracking treasury reward payouts
+    #[account(mut)]
     pub mdnx_token: Account<'info, MdnxToken>,
     
-    /// Treasury token account (source of rewards)
+    /// Treasury token account (source of rewards): the MDNX treasury, or an
+    /// account of the authority's holding a partner reward token
     #[account(
         mut,
-        constraint = treasury.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount,
-        constraint = treasury.key() == mdnx_token.treasury @ ErrorCode::InvalidTokenAccount
+        constraint = treasury.mint != mdnx_token.mint ||
+            treasury.key() == mdnx_token.treasury @ ErrorCode::InvalidTokenAccount,
+        // A self-owned treasury signs through the program, so only this token's own
+        // treasury PDA may be one
+        constraint = !is_program_treasury(&treasury) ||
+            treasury.key() == mdnx_token.treasury @ ErrorCode::InvalidTokenAccount
     )]
     pub treasury: Account<'info, TokenAccount>,
     
-    /// Contributor's token account (destination for rewards)
-    #[account(mut)]
+    /// The contributor's profile
+    #[account(
+        mut,
+        seeds = [CONTRIBUTOR_PROFILE_SEED, contribution.contributor.as_ref()],
+        bump = contributor_profile.bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+    
+    /// Contributor's token account in the reward mint (destination for rewards,
+    /// and for bond refunds when rewards are paid in MDNX)
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contribution.contributor @ ErrorCode::InvalidTokenAccount,
+        constraint = contributor_token_account.mint == treasury.mint @ ErrorCode::InvalidTokenAccount
+    )]
     pub contributor_token_account: Account<'info, TokenAccount>,
     
-    /// Authority (must be model owner)
+    /// Contributor's MDNX token account for the bond refund (required for
+    /// bonded contributions rewarded in a partner token)
+    #[account(
+        mut,
+        constraint = contributor_bond_account.owner == contribution.contributor @ ErrorCode::InvalidTokenAccount,
+        constraint = contributor_bond_account.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contributor_bond_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Vault holding contribution bonds
+    #[account(
+        mut,
+        seeds = [BOND_VAULT_SEED, mdnx_token.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    /// Escrow holding rewards in the treasury's mint (required when the model
+    /// escrows rewards)
+    #[account(
+        mut,
+        seeds = [REWARD_ESCROW_SEED, treasury.mint.as_ref()],
+        bump
+    )]
+    pub reward_escrow: Option<Account<'info, TokenAccount>>,
+    
+    /// Marks the contributor as counted towards the model's unique contributors
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ModelContributor::LEN,
+        seeds = [MODEL_CONTRIBUTOR_SEED, model_registry.key().as_ref(), contribution.contributor.as_ref()],
+        bump
+    )]
+    pub model_contributor: Account<'info, ModelContributor>,
+    
+    /// Verification linked to the contribution (required if the model demands one)
+    pub verification: Option<Account<'info, Verification>>,
+    
+    /// Authority (must be model owner, pays for the model contributor marker)
+    #[account(mut)]
     pub authority: Signer<'info>,
     
     /// Token program
     pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
 }
 
 /// Context for verifying medical data
@@ -315,22 +1208,80 @@ pub struct ApproveContribution<'info> {
 #[instruction(
     data_hash: String,
     verification_method: String,
-    confidence_score: f64,
+    confidence_score: u16,
     metadata: String,
-    result_details: String
+    result_details: String,
+    valid_until: Option<i64>
 )]
 pub struct VerifyData<'info> {
-    /// Initialize a new data verification record
-    #[account(init, payer = verifier, space = Verification::LEN)]
+    /// The data verification PDA for this data hash and verifier
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = Verification::LEN,
+        seeds = [VERIFICATION_SEED, &sha256_seed(&data_hash), verifier.key().as_ref()],
+        bump
+    )]
     pub verification: Account<'info, Verification>,
     
+    /// Aggregate confidence across all verifiers of this data hash
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerificationAggregate::LEN,
+        seeds = [VERIFICATION_AGGREGATE_SEED, &sha256_seed(&data_hash)],
+        bump
+    )]
+    pub verification_aggregate: Account<'info, VerificationAggregate>,
+    
     /// The model used for verification (optional)
+    #[account(mut)]
     pub model: Option<Account<'info, ModelRegistry>>,
     
+    /// The contribution being validated (optional)
+    pub contribution: Option<Account<'info, Contribution>>,
+    
     /// The verifier (payer)
     #[account(mut)]
     pub verifier: Signer<'info>,
     
+    /// The verifier's track record, created on their first verification
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerifierProfile::LEN,
+        seeds = [VERIFIER_PROFILE_SEED, verifier.key().as_ref()],
+        bump
+    )]
+    pub verifier_profile: Account<'info, VerifierProfile>,
+    
+    /// Minimum confidence per verification method, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [METHOD_POLICY_SEED], bump)]
+    pub method_policy: UncheckedAccount<'info>,
+    
+    /// Global config supplying per-type confidence bounds, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    
+    /// The MDNX token (required when the model charges a verification fee)
+    pub mdnx_token: Option<Account<'info, MdnxToken>>,
+    
+    /// Requester paying the verification fee
+    pub requester: Option<Signer<'info>>,
+    
+    /// Requester's token account paying the verification fee
+    #[account(mut)]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Verifier's token account receiving the verification fee
+    #[account(mut)]
+    pub verifier_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Token program
+    pub token_program: Option<Program<'info, Token>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
@@ -343,22 +1294,80 @@ pub struct VerifyData<'info> {
 #[instruction(
     data_hash: String,
     verification_method: String,
-    confidence_score: f64,
+    confidence_score: u16,
     metadata: String,
-    result_details: String
+    result_details: String,
+    valid_until: Option<i64>
 )]
 pub struct VerifyAnalysis<'info> {
-    /// Initialize a new analysis verification record
-    #[account(init, payer = verifier, space = Verification::LEN)]
+    /// The analysis verification PDA for this data hash and verifier
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = Verification::LEN,
+        seeds = [VERIFICATION_SEED, &sha256_seed(&data_hash), verifier.key().as_ref()],
+        bump
+    )]
     pub verification: Account<'info, Verification>,
     
+    /// Aggregate confidence across all verifiers of this data hash
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerificationAggregate::LEN,
+        seeds = [VERIFICATION_AGGREGATE_SEED, &sha256_seed(&data_hash)],
+        bump
+    )]
+    pub verification_aggregate: Account<'info, VerificationAggregate>,
+    
     /// The model used for analysis (optional)
+    #[account(mut)]
     pub model: Option<Account<'info, ModelRegistry>>,
     
+    /// The contribution being validated (optional)
+    pub contribution: Option<Account<'info, Contribution>>,
+    
     /// The verifier (payer)
     #[account(mut)]
     pub verifier: Signer<'info>,
     
+    /// The verifier's track record, created on their first verification
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerifierProfile::LEN,
+        seeds = [VERIFIER_PROFILE_SEED, verifier.key().as_ref()],
+        bump
+    )]
+    pub verifier_profile: Account<'info, VerifierProfile>,
+    
+    /// Minimum confidence per verification method, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [METHOD_POLICY_SEED], bump)]
+    pub method_policy: UncheckedAccount<'info>,
+    
+    /// Global config supplying per-type confidence bounds, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    
+    /// The MDNX token (required when the model charges a verification fee)
+    pub mdnx_token: Option<Account<'info, MdnxToken>>,
+    
+    /// Requester paying the verification fee
+    pub requester: Option<Signer<'info>>,
+    
+    /// Requester's token account paying the verification fee
+    #[account(mut)]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Verifier's token account receiving the verification fee
+    #[account(mut)]
+    pub verifier_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Token program
+    pub token_program: Option<Program<'info, Token>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
@@ -1,5 +1,170 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
 use crate::errors::ErrorCode;
+use crate::token::DEFAULT_AUTHORITY_TRANSFER_WINDOW;
+use crate::contribution::BPS_DENOMINATOR;
+use crate::config::Config;
+
+/// Seed for model registry PDAs
+pub const MODEL_SEED: &[u8] = b"model";
+
+/// Seed for the global model counter PDA
+pub const MODEL_COUNTER_SEED: &[u8] = b"model_counter";
+
+/// Seed for model index entry PDAs (one per sequential model index)
+pub const MODEL_INDEX_SEED: &[u8] = b"model_index";
+
+/// Maximum model name length (bounded by the PDA seed limit)
+pub const MAX_MODEL_NAME_LEN: usize = 32;
+
+/// Maximum depth of a derived-model lineage chain
+pub const MAX_LINEAGE_DEPTH: u16 = 10;
+
+/// Maximum number of tags per model
+pub const MAX_MODEL_TAGS: usize = 8;
+
+/// Maximum length of a single model tag
+pub const MAX_MODEL_TAG_LEN: usize = 32;
+
+/// Maximum number of verifiers on a model's allowlist
+pub const MAX_AUTHORIZED_VERIFIERS: usize = 8;
+
+/// Maximum length of a model artifact storage URI
+pub const MAX_STORAGE_URI_LEN: usize = 128;
+
+/// Bytes reserved for the description when a model is registered
+pub const DESCRIPTION_BUDGET: usize = 256;
+
+/// Bytes reserved for performance metrics when a model is registered
+pub const PERFORMANCE_METRICS_BUDGET: usize = 512;
+
+/// Maximum description length, reachable by growing the account in update_model
+pub const MAX_DESCRIPTION_LEN: usize = 512;
+
+/// Maximum performance metrics length, reachable by growing the account in update_model
+pub const MAX_PERFORMANCE_METRICS_LEN: usize = 1024;
+
+/// Seed for the global usage leaderboard PDA
+pub const LEADERBOARD_SEED: &[u8] = b"leaderboard";
+
+/// Number of models ranked on the usage leaderboard (bounded by compute budget)
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// Seed for per-rater model rating PDAs
+pub const RATING_SEED: &[u8] = b"rating";
+
+/// Lowest and highest scores accepted by rate_model
+pub const MIN_RATING: u8 = 1;
+pub const MAX_RATING: u8 = 5;
+
+/// Scale applied to ratings before averaging (450 = 4.50 stars)
+pub const RATING_SCALE: u16 = 100;
+
+/// Seed for per-model benchmark result PDAs
+pub const BENCHMARK_SEED: &[u8] = b"benchmark";
+
+/// Maximum benchmark name length (bounded by the PDA seed limit)
+pub const MAX_BENCHMARK_NAME_LEN: usize = 32;
+
+/// Highest share of a derived model's usage fees its parent can claim
+pub const MAX_PARENT_ROYALTY_BPS: u16 = 5_000;
+
+/// Fixed-point scale applied to confidence weights while decaying them
+pub const DECAY_PRECISION: u128 = 1 << 32;
+
+/// Seed for the global model category registry PDA
+pub const CATEGORY_REGISTRY_SEED: &[u8] = b"category_registry";
+
+/// Maximum number of registered model categories
+pub const MAX_CATEGORIES: usize = 16;
+
+/// Maximum length of a model category (matches the model_type budget)
+pub const MAX_CATEGORY_LEN: usize = 32;
+
+/// URI schemes accepted for model artifact storage
+pub const STORAGE_URI_SCHEMES: [&str; 2] = ["ipfs://", "ar://"];
+
+/// Check that a storage URI is bounded and uses a known content-addressed scheme
+pub fn is_valid_storage_uri(uri: &str) -> bool {
+    uri.len() <= MAX_STORAGE_URI_LEN
+        && STORAGE_URI_SCHEMES.iter().any(|scheme| uri.len() > scheme.len() && uri.starts_with(scheme))
+}
+
+/// Check that performance metrics are bounded and shaped like a JSON object;
+/// full JSON parsing is left to off-chain consumers
+pub fn is_valid_performance_metrics(metrics: &str) -> bool {
+    metrics.len() <= MAX_PERFORMANCE_METRICS_LEN
+        && metrics.len() >= 2
+        && metrics.starts_with('{')
+        && metrics.ends_with('}')
+}
+
+/// Validate that a hash is a SHA-256 digest encoded as 64 lowercase hex characters
+pub fn validate_sha256(hash: &str) -> Result<()> {
+    if hash.len() != 64 || !hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+        return Err(ErrorCode::InvalidDataHash.into());
+    }
+    
+    Ok(())
+}
+
+/// Decode a SHA-256 hex string into its 32 raw bytes, for use as a PDA seed
+/// (the hex form is 64 bytes, over the 32-byte seed limit)
+pub fn sha256_seed(hash: &str) -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    for (byte, pair) in seed.iter_mut().zip(hash.as_bytes().chunks(2)) {
+        *byte = pair.iter().fold(0u8, |acc, c| (acc << 4) | (*c as char).to_digit(16).unwrap_or(0) as u8);
+    }
+    seed
+}
+
+/// Fold a basis-point value into a running mean over `old_count` previous values.
+/// Uses the delta form so the divisor is always at least 1 and large counts
+/// never multiply the average up; the result stays between the old mean and the value.
+pub fn incremental_mean(old_avg: u16, old_count: u64, value: u16) -> u16 {
+    if old_count == 0 {
+        return value;
+    }
+    
+    let delta = value as i128 - old_avg as i128;
+    (old_avg as i128 + delta / (old_count as i128 + 1)) as u16
+}
+
+/// Fold a basis-point value carrying `weight` into a mean backed by `old_weight`.
+/// Returns the old mean unchanged when the combined weight is zero.
+pub fn weighted_mean(old_avg: u16, old_weight: u64, value: u16, weight: u64) -> u16 {
+    weighted_mean_wide(old_avg, old_weight as u128, value, weight as u128)
+}
+
+/// weighted_mean over u128 weights, for fixed-point (scaled) weights
+fn weighted_mean_wide(old_avg: u16, old_weight: u128, value: u16, weight: u128) -> u16 {
+    let total_weight = old_weight + weight;
+    if total_weight == 0 {
+        return old_avg;
+    }
+    
+    ((old_avg as u128 * old_weight + value as u128 * weight) / total_weight) as u16
+}
+
+/// Decay a fixed-point weight (below 2^96) by `elapsed` seconds: halved once
+/// per full half-life, then interpolated linearly towards the next halving.
+/// A non-positive half-life disables decay.
+pub fn decay_weight(weight: u128, elapsed: i64, half_life: i64) -> u128 {
+    if half_life <= 0 || elapsed <= 0 {
+        return weight;
+    }
+    
+    let half_life = half_life as u128;
+    let elapsed = elapsed as u128;
+    let halvings = elapsed / half_life;
+    if halvings >= 128 {
+        return 0;
+    }
+    
+    let halved = weight >> halvings;
+    let fraction = (elapsed % half_life) * DECAY_PRECISION / (2 * half_life);
+    halved - halved * fraction / DECAY_PRECISION
+}
 
 /// Model Registry data structure
 #[account]
@@ -19,8 +184,8 @@ pub struct ModelRegistry {
     /// Model hash (SHA-256 of model file)
     pub model_hash: String,
     
-    /// Model accuracy
-    pub accuracy: f64,
+    /// Self-reported model accuracy in basis points (set by the model authority)
+    pub accuracy: u16,
     
     /// Performance metrics (JSON string with additional metrics)
     pub performance_metrics: String,
@@ -34,14 +199,14 @@ pub struct ModelRegistry {
     /// Last update timestamp
     pub updated_at: i64,
     
-    /// Number of contributions
+    /// Number of contributions submitted (rejected ones included, withdrawn ones excluded)
     pub contribution_count: u64,
     
     /// Total number of verifications
     pub verification_count: u64,
     
-    /// Average confidence score
-    pub avg_confidence_score: f64,
+    /// Average confidence score in basis points (stake-weighted for verifications)
+    pub avg_confidence_score: u16,
     
     /// Number of uses
     pub usage_count: u64,
@@ -51,31 +216,640 @@ pub struct ModelRegistry {
     
     /// Original parent model (if derived from another model)
     pub parent_model: Option<Pubkey>,
+    
+    /// Bump of the model registry PDA
+    pub bump: u8,
+    
+    /// Proposed new authority (for two-step ownership transfer)
+    pub proposed_authority: Option<Pubkey>,
+    
+    /// Timestamp of the ownership transfer proposal
+    pub authority_proposal_timestamp: i64,
+    
+    /// Acceptance window of the pending ownership proposal, in seconds
+    pub authority_proposal_window: i64,
+    
+    /// Lifecycle status of the model
+    pub status: ModelStatus,
+    
+    /// Discovery tags (e.g., "radiology", "pediatric")
+    pub tags: Vec<String>,
+    
+    /// Number of derivation steps from the original model (0 for originals)
+    pub lineage_depth: u16,
+    
+    /// Fee in MDNX charged to users per recorded usage (0 for free models)
+    pub usage_fee: u64,
+    
+    /// Total MDNX fees collected from model usage
+    pub total_fees_collected: u64,
+    
+    /// Location of the model artifact (e.g., "ipfs://<cid>" or "ar://<tx>")
+    pub storage_uri: String,
+    
+    /// Accuracy in basis points earned through approved contributions (never set directly)
+    pub computed_accuracy: u16,
+    
+    /// Number of approved contributions that affected computed_accuracy
+    pub accuracy_contribution_count: u64,
+    
+    /// Reward paid for every approved contribution
+    pub base_reward: u64,
+    
+    /// Additional reward per unit of accuracy improvement
+    pub accuracy_multiplier: u64,
+    
+    /// Upper bound on any single contribution reward
+    pub max_reward: u64,
+    
+    /// Total MDNX paid out for approved contributions
+    pub total_rewards_distributed: u64,
+    
+    /// Fee in MDNX paid by requesters to verifiers of this model (0 for unpaid)
+    pub verification_fee: u64,
+    
+    /// Whether contributions need a linked, valid verification before approval
+    pub require_contribution_verification: bool,
+    
+    /// Average user rating, scaled by RATING_SCALE
+    pub average_rating: u16,
+    
+    /// Number of users who rated the model
+    pub rating_count: u64,
+    
+    /// Cumulative verifier weight behind avg_confidence_score
+    pub total_confidence_weight: u64,
+    
+    /// Sequential registration index (see ModelIndexEntry)
+    pub model_index: u64,
+    
+    /// Verifiers allowed to verify model outputs (empty allows anyone)
+    pub authorized_verifiers: Vec<Pubkey>,
+    
+    /// Maximum usages per period (0 for unlimited)
+    pub usage_quota: u64,
+    
+    /// Length of a usage quota period in seconds
+    pub usage_period_seconds: i64,
+    
+    /// Period bucket (timestamp / usage_period_seconds) that period_usage_count belongs to
+    pub usage_period_bucket: i64,
+    
+    /// Usages recorded in the current period bucket
+    pub period_usage_count: u64,
+    
+    /// License governing use and derivation of the model
+    pub license: ModelLicense,
+    
+    /// Share of usage fees paid to the parent model's owner (basis points)
+    pub parent_royalty_bps: u16,
+    
+    /// Half-life of verification confidence weight in seconds (0 disables decay)
+    pub confidence_half_life_seconds: i64,
+    
+    /// When a verification last updated avg_confidence_score
+    pub last_confidence_update: i64,
+    
+    /// Number of approved contributions still standing (reversed ones excluded);
+    /// contribution_count tracks submissions
+    pub approved_contribution_count: u64,
+    
+    /// Number of distinct contributors ever approved for the model
+    pub unique_contributor_count: u64,
+    
+    /// Original author of the model; unlike authority, never changed by
+    /// ownership transfers
+    pub creator: Pubkey,
+    
+    /// Seconds a new verification can be challenged before it counts (0 counts immediately)
+    pub verification_challenge_period: i64,
+    
+    /// Seconds approved rewards are held in escrow before the contributor can
+    /// claim them (0 pays out on approval)
+    pub reward_escrow_period: i64,
+    
+    /// Claimed accuracy improvements (in bps) at or below this are approved
+    /// as soon as they are recorded (None reviews every contribution)
+    pub auto_approve_threshold: Option<u16>,
+    
+    /// Fixed MDNX reward paid from the model's reward pool on auto-approval
+    pub auto_approve_reward: u64,
+}
+
+/// Emitted when the token authority revokes a model's verified flag
+#[event]
+pub struct ModelUnverified {
+    pub model: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a model is archived, carrying its full state so off-chain
+/// consumers can reconstruct the account after it is closed
+#[event]
+pub struct ModelArchived {
+    pub model: Pubkey,
+    pub state: ModelRegistry,
+    pub closed: bool,
+    pub timestamp: i64,
+}
+
+/// Lifecycle status of a model
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ModelStatus {
+    /// Model is available for usage and contributions
+    Active,
+    
+    /// Model is superseded but still usable
+    Deprecated,
+    
+    /// Model is retired and accepts no new usage or contributions
+    Archived,
+}
+
+/// A standardized benchmark result for a model; one per (model, benchmark name)
+/// so consumers can compare models on the same named benchmark
+#[account]
+pub struct Benchmark {
+    /// Benchmarked model
+    pub model: Pubkey,
+    
+    /// Name of the benchmark
+    pub benchmark_name: String,
+    
+    /// SHA-256 hash of the benchmark dataset
+    pub dataset_hash: String,
+    
+    /// Benchmark score (basis points)
+    pub score: u16,
+    
+    /// Account that submitted the result
+    pub submitter: Pubkey,
+    
+    /// When the result was submitted
+    pub timestamp: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Benchmark {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // model
+        4 + MAX_BENCHMARK_NAME_LEN + // benchmark_name (String)
+        4 + 64 + // dataset_hash (String)
+        2 + // score
+        32 + // submitter
+        8 + // timestamp
+        1; // bump
+}
+
+/// Taxonomy of valid model_type strings, managed by the config admin
+#[account]
+pub struct CategoryRegistry {
+    /// Registered model categories
+    pub categories: Vec<String>,
+    
+    /// Bump of the category registry PDA
+    pub bump: u8,
+    
+    /// Minimum registration accuracy for safety-critical model types
+    pub accuracy_floors: Vec<AccuracyFloor>,
+}
+
+/// Minimum accuracy a model type must reach to be registered
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct AccuracyFloor {
+    /// Model type the floor applies to
+    pub model_type: String,
+    
+    /// Minimum accuracy (basis points)
+    pub min_accuracy: u16,
+}
+
+impl CategoryRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_CATEGORIES * (4 + MAX_CATEGORY_LEN) + // categories (Vec<String>)
+        1 + // bump
+        4 + MAX_CATEGORIES * (4 + MAX_CATEGORY_LEN + 2); // accuracy_floors (Vec<AccuracyFloor>)
+    
+    /// Whether the model type is registered (anything goes while the registry is empty)
+    pub fn permits(&self, model_type: &str) -> bool {
+        self.categories.is_empty() || self.categories.iter().any(|category| category == model_type)
+    }
+    
+    /// Minimum accuracy for the model type (0 if it has no floor)
+    pub fn min_accuracy(&self, model_type: &str) -> u16 {
+        self.accuracy_floors
+            .iter()
+            .find(|floor| floor.model_type == model_type)
+            .map_or(0, |floor| floor.min_accuracy)
+    }
+}
+
+/// Reject model types missing from the category registry, or below their
+/// type's accuracy floor, treating an uninitialized registry as empty
+fn check_model_type(category_registry: &AccountInfo, model_type: &str, accuracy: u16) -> Result<()> {
+    if category_registry.data_is_empty() {
+        return Ok(());
+    }
+    
+    let registry = Account::<CategoryRegistry>::try_from(category_registry)?;
+    if !registry.permits(model_type) {
+        return Err(ErrorCode::UnknownModelType.into());
+    }
+    
+    if accuracy < registry.min_accuracy(model_type) {
+        return Err(ErrorCode::AccuracyBelowMinimum.into());
+    }
+    
+    Ok(())
+}
+
+/// Compact snapshot of a model's statistics, returned by get_model_stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ModelStats {
+    /// Current model accuracy (basis points)
+    pub accuracy: u16,
+    
+    /// Number of contributions submitted to the model
+    pub contribution_count: u64,
+    
+    /// Number of those contributions approved and not reversed
+    pub approved_contribution_count: u64,
+    
+    /// Number of distinct contributors ever approved
+    pub unique_contributor_count: u64,
+    
+    /// Number of verifications of the model
+    pub verification_count: u64,
+    
+    /// Number of times the model has been used
+    pub usage_count: u64,
+    
+    /// Average confidence score across usages and verifications (basis points)
+    pub avg_confidence_score: u16,
+    
+    /// Whether the model has been verified
+    pub is_verified: bool,
+}
+
+/// License under which a model is published
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ModelLicense {
+    /// Permissive license; anyone may derive models
+    OpenMIT,
+    
+    /// Anyone may derive models, but derivatives stay non-commercial
+    NonCommercial,
+    
+    /// Only the model's authority may derive models
+    Proprietary,
+}
+
+impl ModelLicense {
+    /// Position from most permissive (0) to most restrictive
+    fn restrictiveness(&self) -> u8 {
+        match self {
+            ModelLicense::OpenMIT => 0,
+            ModelLicense::NonCommercial => 1,
+            ModelLicense::Proprietary => 2,
+        }
+    }
+    
+    /// Whether a model under this license may have a derivative under `derived`;
+    /// derivatives can never be licensed more permissively than their parent
+    pub fn permits_derived(&self, derived: &ModelLicense) -> bool {
+        derived.restrictiveness() >= self.restrictiveness()
+    }
+}
+
+/// Most-used models, sorted by usage count (highest first), so front-ends
+/// can rank models from a single account
+#[account]
+pub struct Leaderboard {
+    /// Ranked entries, at most LEADERBOARD_SIZE
+    pub entries: Vec<LeaderboardEntry>,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// A model's position on the usage leaderboard
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    /// The ranked model
+    pub model: Pubkey,
+    
+    /// The model's usage count when last recorded
+    pub usage_count: u64,
+}
+
+impl Leaderboard {
+    pub const LEN: usize = 8 + // discriminator
+        4 + LEADERBOARD_SIZE * (32 + 8) + // entries (Vec<LeaderboardEntry>)
+        1; // bump
+    
+    /// Update a model's usage count, inserting it when it enters the top
+    /// LEADERBOARD_SIZE and evicting the lowest-ranked entry if full. Ties
+    /// keep the model that reached the count first ahead.
+    pub fn record(&mut self, model: Pubkey, usage_count: u64) {
+        if let Some(index) = self.entries.iter().position(|entry| entry.model == model) {
+            self.entries.remove(index);
+        } else if self.entries.len() >= LEADERBOARD_SIZE {
+            match self.entries.last() {
+                Some(lowest) if lowest.usage_count >= usage_count => return,
+                _ => {
+                    self.entries.pop();
+                }
+            }
+        }
+        
+        let index = self.entries
+            .iter()
+            .position(|entry| entry.usage_count < usage_count)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(index, LeaderboardEntry { model, usage_count });
+    }
+}
+
+/// Rank a model on the usage leaderboard, skipping an uninitialized leaderboard
+fn update_leaderboard(
+    leaderboard: &AccountInfo,
+    program_id: &Pubkey,
+    model: Pubkey,
+    usage_count: u64,
+) -> Result<()> {
+    if leaderboard.data_is_empty() {
+        return Ok(());
+    }
+    
+    let mut board = Account::<Leaderboard>::try_from(leaderboard)?;
+    board.record(model, usage_count);
+    board.exit(program_id)
+}
+
+/// Global count of registered models, handing out sequential indices
+#[account]
+pub struct ModelRegistryCounter {
+    /// Number of models registered so far (the next model's index)
+    pub model_count: u64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ModelRegistryCounter {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // model_count
+        1; // bump
+    
+    /// Hand out the next model index
+    pub fn next_index(&mut self) -> Result<u64> {
+        let index = self.model_count;
+        self.model_count = index
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(index)
+    }
+}
+
+/// Maps a sequential model index to the model's address, letting clients
+/// page through models by deriving PDAs for 0..model_count
+#[account]
+pub struct ModelIndexEntry {
+    /// Model registered at this index
+    pub model: Pubkey,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ModelIndexEntry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // model
+        1; // bump
+}
+
+/// A single user's rating of a model; one per (model, rater) pair
+#[account]
+pub struct Rating {
+    /// Rated model
+    pub model: Pubkey,
+    
+    /// User who submitted the rating
+    pub rater: Pubkey,
+    
+    /// Score between MIN_RATING and MAX_RATING
+    pub score: u8,
+    
+    /// When the rating was submitted
+    pub created_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Rating {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // model
+        32 + // rater
+        1 + // score
+        8 + // created_at
+        1; // bump
 }
 
 impl ModelRegistry {
     pub const LEN: usize = 8 + // discriminator
         64 + // name (string)
-        256 + // description (string)
+        DESCRIPTION_BUDGET + // description (string)
         32 + // version (string)
         32 + // model_type (string)
         64 + // model_hash (string)
-        8 + // accuracy (f64)
-        512 + // performance_metrics (string)
+        2 + // accuracy (u16 basis points)
+        PERFORMANCE_METRICS_BUDGET + // performance_metrics (string)
         32 + // authority
         8 + // created_at
         8 + // updated_at
         8 + // contribution_count
         8 + // verification_count
-        8 + // avg_confidence_score
+        2 + // avg_confidence_score (u16 basis points)
         8 + // usage_count
         1 + // is_verified
-        33; // parent_model (Option<Pubkey>)
+        33 + // parent_model (Option<Pubkey>)
+        1 + // bump
+        33 + // proposed_authority (Option<Pubkey>)
+        8 + // authority_proposal_timestamp
+        8 + // authority_proposal_window
+        4 + // status (enum)
+        4 + MAX_MODEL_TAGS * (4 + MAX_MODEL_TAG_LEN) + // tags (Vec<String>)
+        2 + // lineage_depth
+        8 + // usage_fee
+        8 + // total_fees_collected
+        4 + MAX_STORAGE_URI_LEN + // storage_uri (string)
+        2 + // computed_accuracy (u16 basis points)
+        8 + // accuracy_contribution_count
+        8 + // base_reward
+        8 + // accuracy_multiplier
+        8 + // max_reward
+        8 + // total_rewards_distributed
+        8 + // verification_fee
+        1 + // require_contribution_verification
+        2 + // average_rating
+        8 + // rating_count
+        8 + // total_confidence_weight
+        8 + // model_index
+        4 + MAX_AUTHORIZED_VERIFIERS * 32 + // authorized_verifiers (Vec<Pubkey>)
+        8 + // usage_quota
+        8 + // usage_period_seconds
+        8 + // usage_period_bucket
+        8 + // period_usage_count
+        1 + // license
+        2 + // parent_royalty_bps
+        8 + // confidence_half_life_seconds
+        8 + // last_confidence_update
+        8 + // approved_contribution_count
+        8 + // unique_contributor_count
+        32 + // creator
+        8 + // verification_challenge_period
+        8 + // reward_escrow_period
+        3 + // auto_approve_threshold (Option<u16>)
+        8; // auto_approve_reward
+    
+    /// Account size needed to hold the given description and performance
+    /// metrics (falling back to the stored values), growing past LEN only when
+    /// a field exceeds its registration budget
+    pub fn required_space(
+        &self,
+        description: Option<&String>,
+        performance_metrics: Option<&String>,
+    ) -> usize {
+        let description_len = description.unwrap_or(&self.description).len();
+        let performance_metrics_len = performance_metrics.unwrap_or(&self.performance_metrics).len();
+        
+        Self::LEN
+            + description_len.saturating_sub(DESCRIPTION_BUDGET)
+            + performance_metrics_len.saturating_sub(PERFORMANCE_METRICS_BUDGET)
+    }
+    
+    /// Compute the reward for a contribution:
+    /// base_reward + accuracy_improvement (in bps) * accuracy_multiplier / 10000, capped at max_reward
+    pub fn compute_reward(&self, accuracy_improvement: u16) -> Result<u64> {
+        let improvement = accuracy_improvement.min(BPS_DENOMINATOR);
+        let accuracy_bonus = ((improvement as u128) * (self.accuracy_multiplier as u128)
+            / (BPS_DENOMINATOR as u128)) as u64;
+        let reward = self.base_reward
+            .checked_add(accuracy_bonus)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(reward.min(self.max_reward))
+    }
+    
+    /// Whether a contribution claiming this improvement skips manual review
+    pub fn auto_approves(&self, accuracy_improvement: u16) -> bool {
+        self.auto_approve_threshold
+            .map_or(false, |threshold| accuracy_improvement <= threshold)
+    }
+    
+    /// Portion of a usage fee owed to the parent model's owner (none for original models)
+    pub fn parent_royalty(&self, usage_fee: u64) -> u64 {
+        if self.parent_model.is_none() {
+            return 0;
+        }
+        
+        ((usage_fee as u128) * (self.parent_royalty_bps as u128) / (BPS_DENOMINATOR as u128)) as u64
+    }
+    
+    /// Whether the key may verify this model's outputs (anyone, if the allowlist is empty)
+    pub fn is_authorized_verifier(&self, key: &Pubkey) -> bool {
+        self.authorized_verifiers.is_empty() || self.authorized_verifiers.contains(key)
+    }
+    
+    /// Snapshot of the model's headline statistics
+    pub fn stats(&self) -> ModelStats {
+        ModelStats {
+            accuracy: self.accuracy,
+            contribution_count: self.contribution_count,
+            approved_contribution_count: self.approved_contribution_count,
+            unique_contributor_count: self.unique_contributor_count,
+            verification_count: self.verification_count,
+            usage_count: self.usage_count,
+            avg_confidence_score: self.avg_confidence_score,
+            is_verified: self.is_verified,
+        }
+    }
+    
+    /// Count a usage against the quota for the current period, starting a
+    /// fresh bucket when the period has rolled over
+    pub fn consume_usage_quota(&mut self, current_timestamp: i64) -> Result<()> {
+        if self.usage_quota == 0 {
+            return Ok(());
+        }
+        
+        let bucket = current_timestamp / self.usage_period_seconds;
+        if bucket != self.usage_period_bucket {
+            self.usage_period_bucket = bucket;
+            self.period_usage_count = 0;
+        }
+        
+        if self.period_usage_count >= self.usage_quota {
+            return Err(ErrorCode::UsageQuotaExceeded.into());
+        }
+        
+        self.period_usage_count = self.period_usage_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(())
+    }
+    
+    /// Count a usage and fold its confidence score into the running average
+    pub fn record_usage_stats(&mut self, confidence_score: u16) -> Result<()> {
+        let old_count = self.usage_count;
+        self.usage_count = old_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.avg_confidence_score = incremental_mean(self.avg_confidence_score, old_count, confidence_score);
+        
+        Ok(())
+    }
+    
+    /// Count a verification and fold its confidence score, weighted by the
+    /// verifier's stake, into the running average. Earlier verifications
+    /// lose weight with the model's confidence half-life so recent ones dominate.
+    pub fn record_verification(
+        &mut self,
+        confidence_score: u16,
+        weight: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        self.verification_count = self.verification_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        let old_weight = decay_weight(
+            self.total_confidence_weight as u128 * DECAY_PRECISION,
+            current_timestamp - self.last_confidence_update,
+            self.confidence_half_life_seconds,
+        );
+        let weight = weight as u128 * DECAY_PRECISION;
+        self.avg_confidence_score = weighted_mean_wide(self.avg_confidence_score, old_weight, confidence_score, weight);
+        
+        // Store the combined weight back in whole units, rounded to nearest
+        let total_weight = (old_weight + weight + DECAY_PRECISION / 2) / DECAY_PRECISION;
+        self.total_confidence_weight = u64::try_from(total_weight)
+            .map_err(|_| ErrorCode::ArithmeticOverflow)?;
+        self.last_confidence_update = current_timestamp;
+        
+        Ok(())
+    }
 }
 
 /// Model operation implementations
 pub mod model_operations {
     use super::*;
+    use std::collections::BTreeMap;
     
     /// Register a new AI model
     pub fn register_model(
@@ -85,22 +859,67 @@ pub mod model_operations {
         version: String,
         model_type: String,
         model_hash: String,
-        accuracy: f64,
+        accuracy: u16,
         performance_metrics: String,
+        storage_uri: String,
+        license: ModelLicense,
     ) -> Result<()> {
-        let model = &mut ctx.accounts.model_registry;
-        let authority = &ctx.accounts.authority;
-        let current_timestamp = Clock::get()?.unix_timestamp;
-        
-        // Validate inputs
-        if model_hash.len() < 16 {
-            return Err(ErrorCode::InvalidModelHash.into());
-        }
-        
-        if accuracy < 0.0 || accuracy > 1.0 {
-            return Err(ErrorCode::InvalidAccuracyValue.into());
-        }
-        
+        register_model_accounts(
+            ctx.accounts, &ctx.bumps, name, description, version, model_type, model_hash, accuracy,
+            performance_metrics, storage_uri, license
+        )
+    }
+    
+    /// Initialize a model from its registration accounts; shared with
+    /// register_model_with_contribution, which nests these accounts
+    pub fn register_model_accounts(
+        accounts: &mut crate::RegisterModel,
+        bumps: &BTreeMap<String, u8>,
+        name: String,
+        description: String,
+        version: String,
+        model_type: String,
+        model_hash: String,
+        accuracy: u16,
+        performance_metrics: String,
+        storage_uri: String,
+        license: ModelLicense,
+    ) -> Result<()> {
+        let model = &mut accounts.model_registry;
+        let authority = &accounts.authority;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // An initialized PDA means this authority already registered the name
+        if model.authority != Pubkey::default() {
+            return Err(ErrorCode::ModelAlreadyRegistered.into());
+        }
+        
+        // Validate inputs
+        if name.is_empty() || name.len() > MAX_MODEL_NAME_LEN {
+            return Err(ErrorCode::InvalidModelName.into());
+        }
+        
+        if description.len() > DESCRIPTION_BUDGET ||
+           performance_metrics.len() > PERFORMANCE_METRICS_BUDGET {
+            return Err(ErrorCode::FieldTooLong.into());
+        }
+        
+        if !is_valid_performance_metrics(&performance_metrics) {
+            return Err(ErrorCode::InvalidMetricsFormat.into());
+        }
+        
+        validate_sha256(&model_hash).map_err(|_| ErrorCode::InvalidModelHash)?;
+        
+        if accuracy > BPS_DENOMINATOR {
+            return Err(ErrorCode::InvalidAccuracyValue.into());
+        }
+        
+        if !is_valid_storage_uri(&storage_uri) {
+            return Err(ErrorCode::InvalidStorageUri.into());
+        }
+        
+        check_model_type(&accounts.category_registry, &model_type, accuracy)?;
+        
         // Initialize model
         model.name = name;
         model.description = description;
@@ -110,14 +929,61 @@ pub mod model_operations {
         model.accuracy = accuracy;
         model.performance_metrics = performance_metrics;
         model.authority = authority.key();
+        model.creator = authority.key();
         model.created_at = current_timestamp;
         model.updated_at = current_timestamp;
         model.contribution_count = 0;
         model.verification_count = 0;
-        model.avg_confidence_score = 0.0;
+        model.avg_confidence_score = 0;
         model.usage_count = 0;
         model.is_verified = false;
         model.parent_model = None;
+        model.lineage_depth = 0;
+        model.bump = *bumps.get("model_registry").unwrap();
+        model.proposed_authority = None;
+        model.authority_proposal_timestamp = 0;
+        model.authority_proposal_window = 0;
+        model.status = ModelStatus::Active;
+        model.tags = Vec::new();
+        model.usage_fee = accounts.config
+            .as_ref()
+            .map_or(0, |config| config.default_usage_fee);
+        model.total_fees_collected = 0;
+        model.storage_uri = storage_uri;
+        model.computed_accuracy = 0;
+        model.accuracy_contribution_count = 0;
+        model.base_reward = 0;
+        model.accuracy_multiplier = 0;
+        model.max_reward = 0;
+        model.total_rewards_distributed = 0;
+        model.verification_fee = accounts.config
+            .as_ref()
+            .map_or(0, |config| config.default_verification_fee);
+        model.require_contribution_verification = false;
+        model.average_rating = 0;
+        model.rating_count = 0;
+        model.total_confidence_weight = 0;
+        model.authorized_verifiers = Vec::new();
+        model.usage_quota = 0;
+        model.usage_period_seconds = 0;
+        model.usage_period_bucket = 0;
+        model.period_usage_count = 0;
+        model.license = license;
+        model.parent_royalty_bps = 0;
+        model.confidence_half_life_seconds = 0;
+        model.verification_challenge_period = 0;
+        model.reward_escrow_period = 0;
+        model.auto_approve_threshold = None;
+        model.auto_approve_reward = 0;
+        model.last_confidence_update = current_timestamp;
+        model.approved_contribution_count = 0;
+        model.unique_contributor_count = 0;
+        
+        // Assign the next sequential index
+        model.model_index = accounts.model_counter.next_index()?;
+        let index_entry = &mut accounts.model_index;
+        index_entry.model = model.key();
+        index_entry.bump = *bumps.get("model_index").unwrap();
         
         msg!("Model registered: {} v{}", model.name, model.version);
         Ok(())
@@ -130,18 +996,25 @@ pub mod model_operations {
         description: Option<String>,
         version: Option<String>,
         model_hash: Option<String>,
-        accuracy: Option<f64>,
+        accuracy: Option<u16>,
         performance_metrics: Option<String>,
+        storage_uri: Option<String>,
     ) -> Result<()> {
         let model = &mut ctx.accounts.model_registry;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
-        // Update fields if provided
-        if let Some(name_val) = name {
-            model.name = name_val;
+        // The name seeds the model's PDA, so it cannot change
+        if name.map_or(false, |name_val| name_val != model.name) {
+            return Err(ErrorCode::ModelNameImmutable.into());
         }
         
+        // Update fields if provided
+        
+        // Longer fields were already paid for by the realloc in UpdateModel
         if let Some(description_val) = description {
+            if description_val.len() > MAX_DESCRIPTION_LEN {
+                return Err(ErrorCode::FieldTooLong.into());
+            }
             model.description = description_val;
         }
         
@@ -150,27 +1023,648 @@ pub mod model_operations {
         }
         
         if let Some(model_hash_val) = model_hash {
-            if model_hash_val.len() < 16 {
-                return Err(ErrorCode::InvalidModelHash.into());
-            }
+            validate_sha256(&model_hash_val).map_err(|_| ErrorCode::InvalidModelHash)?;
             model.model_hash = model_hash_val;
         }
         
         if let Some(accuracy_val) = accuracy {
-            if accuracy_val < 0.0 || accuracy_val > 1.0 {
+            if accuracy_val > BPS_DENOMINATOR {
                 return Err(ErrorCode::InvalidAccuracyValue.into());
             }
             model.accuracy = accuracy_val;
         }
         
         if let Some(performance_metrics_val) = performance_metrics {
+            if performance_metrics_val.len() > MAX_PERFORMANCE_METRICS_LEN {
+                return Err(ErrorCode::FieldTooLong.into());
+            }
+            if !is_valid_performance_metrics(&performance_metrics_val) {
+                return Err(ErrorCode::InvalidMetricsFormat.into());
+            }
             model.performance_metrics = performance_metrics_val;
         }
         
-        // Update timestamp
+        if let Some(storage_uri_val) = storage_uri {
+            if !is_valid_storage_uri(&storage_uri_val) {
+                return Err(ErrorCode::InvalidStorageUri.into());
+            }
+            model.storage_uri = storage_uri_val;
+        }
+        
+        // Update timestamp
+        model.updated_at = current_timestamp;
+        
+        msg!("Model updated: {} v{}", model.name, model.version);
+        Ok(())
+    }
+    
+    /// Propose a new authority for a model
+    pub fn propose_model_transfer(
+        ctx: Context<ProposeModelTransfer>,
+        new_authority: Pubkey,
+        window: Option<i64>,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        if new_authority == model.authority {
+            return Err(ErrorCode::SameAuthorityProposed.into());
+        }
+        
+        if new_authority == Pubkey::default() {
+            return Err(ErrorCode::InvalidProposedAuthority.into());
+        }
+        
+        let window = window.unwrap_or(DEFAULT_AUTHORITY_TRANSFER_WINDOW);
+        if window <= 0 {
+            return Err(ErrorCode::InvalidTransferWindow.into());
+        }
+        
+        // Set proposed authority
+        model.proposed_authority = Some(new_authority);
+        model.authority_proposal_timestamp = current_timestamp;
+        model.authority_proposal_window = window;
+        model.updated_at = current_timestamp;
+        
+        msg!("Model transfer proposed to: {}", new_authority);
+        Ok(())
+    }
+    
+    /// Accept a model ownership transfer (must be called by proposed authority)
+    pub fn accept_model_transfer(
+        ctx: Context<AcceptModelTransfer>,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Verify proposed authority exists
+        let proposed_authority = model.proposed_authority
+            .ok_or(ErrorCode::InvalidAuthorityTransferState)?;
+        
+        // Verify caller is the proposed authority
+        require_keys_eq!(
+            proposed_authority,
+            ctx.accounts.new_authority.key(),
+            ErrorCode::UnauthorizedAccess
+        );
+        
+        // Check if proposal hasn't expired
+        let proposal_age = current_timestamp - model.authority_proposal_timestamp;
+        if proposal_age > model.authority_proposal_window {
+            return Err(ErrorCode::AuthorityTransferExpired.into());
+        }
+        
+        // Transfer ownership
+        model.authority = proposed_authority;
+        model.proposed_authority = None;
+        model.authority_proposal_timestamp = 0;
+        model.authority_proposal_window = 0;
+        model.updated_at = current_timestamp;
+        
+        msg!("Model transfer accepted, new authority: {}", proposed_authority);
+        Ok(())
+    }
+    
+    /// Set the lifecycle status of a model
+    pub fn set_model_status(
+        ctx: Context<SetModelStatus>,
+        status: ModelStatus,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        model.status = status;
+        model.updated_at = current_timestamp;
+        
+        msg!("Model status updated: {} v{}", model.name, model.version);
+        Ok(())
+    }
+    
+    /// Archive a model, logging its full state and optionally closing the
+    /// account to reclaim its rent
+    pub fn archive_model(
+        ctx: Context<ArchiveModel>,
+        close: bool,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        model.status = ModelStatus::Archived;
+        model.updated_at = current_timestamp;
+        
+        emit!(ModelArchived {
+            model: model.key(),
+            state: ModelRegistry::clone(model),
+            closed: close,
+            timestamp: current_timestamp,
+        });
+        
+        if close {
+            model.close(ctx.accounts.authority.to_account_info())?;
+            msg!("Model archived and closed: {}", model.key());
+        } else {
+            msg!("Model archived: {} v{}", model.name, model.version);
+        }
+        Ok(())
+    }
+    
+    /// Consolidate a source model's statistics into a target model and
+    /// archive the source (signed by both authorities)
+    pub fn merge_models(
+        ctx: Context<MergeModels>,
+    ) -> Result<()> {
+        let target = &mut ctx.accounts.target_model;
+        let source = &mut ctx.accounts.source_model;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // An archived model is retired (or already merged)
+        if target.status == ModelStatus::Archived || source.status == ModelStatus::Archived {
+            return Err(ErrorCode::ModelArchived.into());
+        }
+        
+        // Weight each confidence average by the samples behind it
+        let target_samples = target.usage_count
+            .checked_add(target.verification_count)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let source_samples = source.usage_count
+            .checked_add(source.verification_count)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        target.avg_confidence_score = weighted_mean(
+            target.avg_confidence_score,
+            target_samples,
+            source.avg_confidence_score,
+            source_samples,
+        );
+        
+        target.contribution_count = target.contribution_count
+            .checked_add(source.contribution_count)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        target.verification_count = target.verification_count
+            .checked_add(source.verification_count)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        target.usage_count = target.usage_count
+            .checked_add(source.usage_count)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        target.total_confidence_weight = target.total_confidence_weight
+            .checked_add(source.total_confidence_weight)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        target.updated_at = current_timestamp;
+        
+        source.status = ModelStatus::Archived;
+        source.updated_at = current_timestamp;
+        
+        msg!("Model {} merged into {}", source.key(), target.key());
+        Ok(())
+    }
+    
+    /// Set the MDNX fee charged per model usage
+    pub fn set_usage_fee(
+        ctx: Context<SetUsageFee>,
+        usage_fee: u64,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        model.usage_fee = usage_fee;
+        model.updated_at = current_timestamp;
+        
+        msg!("Usage fee for model {} set to {}", model.name, usage_fee);
+        Ok(())
+    }
+    
+    /// Set the share of a derived model's usage fees paid to its parent's owner
+    pub fn set_parent_royalty(
+        ctx: Context<SetParentRoyalty>,
+        royalty_bps: u16,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.derived_model;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if royalty_bps > MAX_PARENT_ROYALTY_BPS {
+            return Err(ErrorCode::InvalidRoyalty.into());
+        }
+        
+        model.parent_royalty_bps = royalty_bps;
+        model.updated_at = current_timestamp;
+        
+        msg!("Parent royalty for model {} set to {} bps", model.name, royalty_bps);
+        Ok(())
+    }
+    
+    /// Set the half-life over which verification confidence loses weight
+    pub fn set_confidence_half_life(
+        ctx: Context<SetConfidenceHalfLife>,
+        half_life_seconds: i64,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if half_life_seconds < 0 {
+            return Err(ErrorCode::InvalidHalfLife.into());
+        }
+        
+        model.confidence_half_life_seconds = half_life_seconds;
+        model.updated_at = current_timestamp;
+        
+        msg!("Confidence half-life for model {} set to {}s", model.name, half_life_seconds);
+        Ok(())
+    }
+    
+    /// Set how long new verifications of the model can be challenged
+    /// before they count towards it (0 counts them immediately)
+    pub fn set_verification_challenge_period(
+        ctx: Context<SetVerificationChallengePeriod>,
+        challenge_period: i64,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if challenge_period < 0 {
+            return Err(ErrorCode::InvalidChallengePeriod.into());
+        }
+        
+        model.verification_challenge_period = challenge_period;
+        model.updated_at = current_timestamp;
+        
+        msg!("Verification challenge period for model {} set to {}s", model.name, challenge_period);
+        Ok(())
+    }
+    
+    /// Set how long approved rewards are escrowed before they can be claimed
+    pub fn set_reward_escrow_period(
+        ctx: Context<SetRewardEscrowPeriod>,
+        escrow_period: i64,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if escrow_period < 0 {
+            return Err(ErrorCode::InvalidEscrowPeriod.into());
+        }
+        
+        model.reward_escrow_period = escrow_period;
+        model.updated_at = current_timestamp;
+        
+        msg!("Reward escrow period for model {} set to {}s", model.name, escrow_period);
+        Ok(())
+    }
+    
+    /// Auto-approve contributions claiming at most `threshold` bps of
+    /// improvement, paying each a fixed reward from the model's reward pool
+    /// (None restores manual review)
+    pub fn set_auto_approve_policy(
+        ctx: Context<SetAutoApprovePolicy>,
+        threshold: Option<u16>,
+        reward: u64,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if threshold.map_or(false, |threshold| threshold > BPS_DENOMINATOR) {
+            return Err(ErrorCode::InvalidAutoApprovePolicy.into());
+        }
+        
+        if threshold.is_none() && reward > 0 {
+            return Err(ErrorCode::InvalidAutoApprovePolicy.into());
+        }
+        
+        model.auto_approve_threshold = threshold;
+        model.auto_approve_reward = reward;
+        model.updated_at = current_timestamp;
+        
+        msg!("Auto-approve policy for model {} set to {:?} bps with reward {}", model.name, threshold, reward);
+        Ok(())
+    }
+    
+    /// Cap usages per period (a quota of 0 removes the cap)
+    pub fn set_usage_quota(
+        ctx: Context<SetUsageQuota>,
+        usage_quota: u64,
+        usage_period_seconds: i64,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if usage_quota > 0 && usage_period_seconds <= 0 {
+            return Err(ErrorCode::InvalidUsageQuota.into());
+        }
+        
+        model.usage_quota = usage_quota;
+        model.usage_period_seconds = usage_period_seconds;
+        model.usage_period_bucket = 0;
+        model.period_usage_count = 0;
+        model.updated_at = current_timestamp;
+        
+        msg!("Usage quota for model {} set to {} per {}s", model.name, usage_quota, usage_period_seconds);
+        Ok(())
+    }
+    
+    /// Set the MDNX fee paid to verifiers of the model
+    pub fn set_verification_fee(
+        ctx: Context<SetVerificationFee>,
+        verification_fee: u64,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        model.verification_fee = verification_fee;
+        model.updated_at = current_timestamp;
+        
+        msg!("Verification fee for model {} set to {}", model.name, verification_fee);
+        Ok(())
+    }
+    
+    /// Replace the model's verifier allowlist (an empty list opens verification to anyone)
+    pub fn set_model_verifiers(
+        ctx: Context<SetModelVerifiers>,
+        verifiers: Vec<Pubkey>,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if verifiers.len() > MAX_AUTHORIZED_VERIFIERS {
+            return Err(ErrorCode::InvalidVerifierList.into());
+        }
+        
+        for (index, verifier) in verifiers.iter().enumerate() {
+            if verifiers[..index].contains(verifier) {
+                return Err(ErrorCode::InvalidVerifierList.into());
+            }
+        }
+        
+        model.authorized_verifiers = verifiers;
+        model.updated_at = current_timestamp;
+        
+        msg!("Model {} now has {} authorized verifiers", model.name, model.authorized_verifiers.len());
+        Ok(())
+    }
+    
+    /// Set whether contribution approvals require a linked verification
+    pub fn set_verification_requirement(
+        ctx: Context<SetVerificationRequirement>,
+        required: bool,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        model.require_contribution_verification = required;
+        model.updated_at = current_timestamp;
+        
+        msg!("Contribution verification for model {} required: {}", model.name, required);
+        Ok(())
+    }
+    
+    /// Create the global model counter
+    pub fn init_model_counter(ctx: Context<InitModelCounter>) -> Result<()> {
+        let counter = &mut ctx.accounts.model_counter;
+        
+        counter.model_count = 0;
+        counter.bump = *ctx.bumps.get("model_counter").unwrap();
+        
+        msg!("Model counter initialized");
+        Ok(())
+    }
+    
+    /// Create the global usage leaderboard
+    pub fn init_leaderboard(ctx: Context<InitLeaderboard>) -> Result<()> {
+        let leaderboard = &mut ctx.accounts.leaderboard;
+        
+        leaderboard.entries = Vec::new();
+        leaderboard.bump = *ctx.bumps.get("leaderboard").unwrap();
+        
+        msg!("Usage leaderboard initialized");
+        Ok(())
+    }
+    
+    /// Register a valid model type (config admin only)
+    pub fn add_category(
+        ctx: Context<AddCategory>,
+        category: String,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.category_registry;
+        
+        // Validate inputs
+        if category.is_empty()
+            || category.len() > MAX_CATEGORY_LEN
+            || registry.categories.contains(&category)
+            || registry.categories.len() >= MAX_CATEGORIES
+        {
+            return Err(ErrorCode::InvalidCategoryList.into());
+        }
+        
+        registry.bump = *ctx.bumps.get("category_registry").unwrap();
+        registry.categories.push(category.clone());
+        
+        msg!("Model category added: {}", category);
+        Ok(())
+    }
+    
+    /// Remove a model type (config admin only); models already using it keep it
+    pub fn remove_category(
+        ctx: Context<RemoveCategory>,
+        category: String,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.category_registry;
+        
+        let index = registry.categories
+            .iter()
+            .position(|existing| *existing == category)
+            .ok_or(ErrorCode::InvalidCategoryList)?;
+        
+        registry.categories.remove(index);
+        
+        msg!("Model category removed: {}", category);
+        Ok(())
+    }
+    
+    /// Set the minimum registration accuracy for a model type (config admin
+    /// only); a floor of 0 removes it
+    pub fn set_category_min_accuracy(
+        ctx: Context<SetCategoryMinAccuracy>,
+        model_type: String,
+        min_accuracy: u16,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.category_registry;
+        
+        // Validate inputs
+        if model_type.is_empty() || model_type.len() > MAX_CATEGORY_LEN {
+            return Err(ErrorCode::InvalidCategoryList.into());
+        }
+        
+        if min_accuracy > BPS_DENOMINATOR {
+            return Err(ErrorCode::InvalidAccuracyValue.into());
+        }
+        
+        registry.bump = *ctx.bumps.get("category_registry").unwrap();
+        
+        let existing = registry.accuracy_floors
+            .iter()
+            .position(|floor| floor.model_type == model_type);
+        match (existing, min_accuracy) {
+            (Some(index), 0) => {
+                registry.accuracy_floors.remove(index);
+            }
+            (Some(index), _) => {
+                registry.accuracy_floors[index].min_accuracy = min_accuracy;
+            }
+            (None, 0) => {}
+            (None, _) => {
+                if registry.accuracy_floors.len() >= MAX_CATEGORIES {
+                    return Err(ErrorCode::InvalidCategoryList.into());
+                }
+                registry.accuracy_floors.push(AccuracyFloor {
+                    model_type: model_type.clone(),
+                    min_accuracy,
+                });
+            }
+        }
+        
+        msg!("Minimum accuracy for {} set to {}", model_type, min_accuracy);
+        Ok(())
+    }
+    
+    /// Rate a model; the Rating PDA makes this a one-time action per user
+    pub fn rate_model(
+        ctx: Context<RateModel>,
+        score: u8,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let rating = &mut ctx.accounts.rating;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if !(MIN_RATING..=MAX_RATING).contains(&score) {
+            return Err(ErrorCode::InvalidRating.into());
+        }
+        
+        rating.model = model.key();
+        rating.rater = ctx.accounts.rater.key();
+        rating.score = score;
+        rating.created_at = current_timestamp;
+        rating.bump = *ctx.bumps.get("rating").unwrap();
+        
+        let old_count = model.rating_count;
+        model.rating_count = old_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        model.average_rating = incremental_mean(
+            model.average_rating,
+            old_count,
+            score as u16 * RATING_SCALE,
+        );
+        
+        msg!("Model {} rated {} by {}", model.name, score, rating.rater);
+        Ok(())
+    }
+    
+    /// Attach a standardized benchmark result to a model
+    pub fn submit_benchmark(
+        ctx: Context<SubmitBenchmark>,
+        benchmark_name: String,
+        dataset_hash: String,
+        score: u16,
+    ) -> Result<()> {
+        let model = &ctx.accounts.model_registry;
+        let benchmark = &mut ctx.accounts.benchmark;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if benchmark_name.is_empty() || benchmark_name.len() > MAX_BENCHMARK_NAME_LEN {
+            return Err(ErrorCode::InvalidBenchmarkName.into());
+        }
+        
+        validate_sha256(&dataset_hash)?;
+        
+        if score > BPS_DENOMINATOR {
+            return Err(ErrorCode::InvalidAccuracyValue.into());
+        }
+        
+        benchmark.model = model.key();
+        benchmark.benchmark_name = benchmark_name;
+        benchmark.dataset_hash = dataset_hash;
+        benchmark.score = score;
+        benchmark.submitter = ctx.accounts.submitter.key();
+        benchmark.timestamp = current_timestamp;
+        benchmark.bump = *ctx.bumps.get("benchmark").unwrap();
+        
+        msg!("Benchmark {} submitted for model {}: {}", benchmark.benchmark_name, model.name, score);
+        Ok(())
+    }
+    
+    /// Set the reward policy applied when contributions are approved
+    pub fn set_reward_policy(
+        ctx: Context<SetRewardPolicy>,
+        base_reward: u64,
+        accuracy_multiplier: u64,
+        max_reward: u64,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        if base_reward > max_reward {
+            return Err(ErrorCode::InvalidRewardPolicy.into());
+        }
+        
+        model.base_reward = base_reward;
+        model.accuracy_multiplier = accuracy_multiplier;
+        model.max_reward = max_reward;
+        model.updated_at = current_timestamp;
+        
+        msg!("Reward policy updated for model {}", model.name);
+        Ok(())
+    }
+    
+    /// Add a discovery tag to a model
+    pub fn add_model_tag(
+        ctx: Context<ManageModelTags>,
+        tag: String,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        if tag.is_empty() || tag.len() > MAX_MODEL_TAG_LEN {
+            return Err(ErrorCode::InvalidModelTag.into());
+        }
+        
+        if model.tags.contains(&tag) {
+            return Err(ErrorCode::DuplicateModelTag.into());
+        }
+        
+        if model.tags.len() >= MAX_MODEL_TAGS {
+            return Err(ErrorCode::TooManyModelTags.into());
+        }
+        
+        msg!("Tag added to model {}: {}", model.name, tag);
+        model.tags.push(tag);
+        model.updated_at = current_timestamp;
+        
+        Ok(())
+    }
+    
+    /// Remove a discovery tag from a model
+    pub fn remove_model_tag(
+        ctx: Context<ManageModelTags>,
+        tag: String,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        let position = model.tags.iter()
+            .position(|existing| *existing == tag)
+            .ok_or(ErrorCode::ModelTagNotFound)?;
+        
+        model.tags.remove(position);
         model.updated_at = current_timestamp;
         
-        msg!("Model updated: {} v{}", model.name, model.version);
+        msg!("Tag removed from model {}: {}", model.name, tag);
         Ok(())
     }
     
@@ -181,6 +1675,13 @@ pub mod model_operations {
         let model = &mut ctx.accounts.model_registry;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
+        // Only the token authority can verify models
+        require_keys_eq!(
+            ctx.accounts.verifier.key(),
+            ctx.accounts.mdnx_token.authority,
+            ErrorCode::UnauthorizedAccess
+        );
+        
         // Set model as verified
         model.is_verified = true;
         model.updated_at = current_timestamp;
@@ -189,34 +1690,138 @@ pub mod model_operations {
         Ok(())
     }
     
+    /// Revoke a model's verification, e.g. when it is found to be defective
+    pub fn unverify_model(
+        ctx: Context<UnverifyModel>,
+    ) -> Result<()> {
+        let model = &mut ctx.accounts.model_registry;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only the token authority can unverify models
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            ctx.accounts.mdnx_token.authority,
+            ErrorCode::UnauthorizedAccess
+        );
+        
+        model.is_verified = false;
+        model.updated_at = current_timestamp;
+        
+        emit!(ModelUnverified {
+            model: model.key(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: current_timestamp,
+        });
+        
+        msg!("Model unverified: {} v{}", model.name, model.version);
+        Ok(())
+    }
+    
+    /// Publish a model's statistics as return data so clients can simulate
+    /// the instruction instead of decoding the account layout
+    pub fn get_model_stats(
+        ctx: Context<GetModelStats>,
+    ) -> Result<()> {
+        let model = &ctx.accounts.model_registry;
+        
+        let stats = model.stats();
+        anchor_lang::solana_program::program::set_return_data(&stats.try_to_vec()?);
+        
+        msg!("Stats for model {}: accuracy {}, usage count {}", model.name, stats.accuracy, stats.usage_count);
+        Ok(())
+    }
+    
     /// Record model usage
     pub fn record_usage(
         ctx: Context<RecordModelUsage>,
-        confidence_score: f64,
+        confidence_score: u16,
     ) -> Result<()> {
         let model = &mut ctx.accounts.model_registry;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
+        // Archived models accept no new usage
+        if model.status == ModelStatus::Archived {
+            return Err(ErrorCode::ModelArchived.into());
+        }
+        
         // Validate confidence score
-        if confidence_score < 0.0 || confidence_score > 1.0 {
+        if confidence_score > BPS_DENOMINATOR {
             return Err(ErrorCode::InvalidConfidenceScore.into());
         }
         
-        // Update usage statistics
-        model.usage_count += 1;
+        // Callers may require a still-valid verification of the model
+        if let Some(verification) = &ctx.accounts.verification {
+            if !verification.is_valid_at(current_timestamp) {
+                return Err(ErrorCode::VerificationExpired.into());
+            }
+        }
         
-        // Update average confidence score
-        let old_avg = model.avg_confidence_score;
-        let old_count = model.usage_count - 1;
+        // Enforce the owner's usage quota for the current period
+        model.consume_usage_quota(current_timestamp)?;
         
-        if old_count == 0 {
-            model.avg_confidence_score = confidence_score;
-        } else {
-            model.avg_confidence_score = (old_avg * (old_count as f64) + confidence_score) / (model.usage_count as f64);
+        // Collect the usage fee for the model owner
+        let usage_fee = model.usage_fee;
+        if usage_fee > 0 {
+            // Token transfers are halted while the program is paused
+            if ctx.accounts.mdnx_token.is_paused {
+                return Err(ErrorCode::ProgramPaused.into());
+            }
+            
+            if usage_fee > ctx.accounts.user_token_account.amount {
+                return Err(ErrorCode::InsufficientTokenBalance.into());
+            }
+            
+            // Derived models pass a royalty up to the parent model's owner
+            let royalty = model.parent_royalty(usage_fee);
+            if royalty > 0 {
+                let (parent_model, parent_fee_destination) = match (
+                    &ctx.accounts.parent_model,
+                    &ctx.accounts.parent_fee_destination,
+                ) {
+                    (Some(parent_model), Some(parent_fee_destination)) => (parent_model, parent_fee_destination),
+                    _ => return Err(ErrorCode::MissingRoyaltyAccounts.into()),
+                };
+                
+                if parent_fee_destination.owner != parent_model.authority
+                    || parent_fee_destination.mint != ctx.accounts.mdnx_token.mint {
+                    return Err(ErrorCode::InvalidTokenAccount.into());
+                }
+                
+                let royalty_ctx = CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.user_token_account.to_account_info(),
+                        to: parent_fee_destination.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                );
+                
+                token::transfer(royalty_ctx, royalty)?;
+            }
+            
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    to: ctx.accounts.fee_destination.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            );
+            
+            token::transfer(transfer_ctx, usage_fee - royalty)?;
+            
+            model.total_fees_collected = model.total_fees_collected
+                .checked_add(usage_fee)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
         }
         
+        // Update usage statistics
+        model.record_usage_stats(confidence_score)?;
+        
         model.updated_at = current_timestamp;
         
+        update_leaderboard(&ctx.accounts.leaderboard, ctx.program_id, model.key(), model.usage_count)?;
+        
         msg!("Model usage recorded for {} v{}, new usage count: {}", 
              model.name, model.version, model.usage_count);
         Ok(())
@@ -230,23 +1835,70 @@ pub mod model_operations {
         version: String,
         model_type: String,
         model_hash: String,
-        accuracy: f64,
+        accuracy: u16,
         performance_metrics: String,
+        storage_uri: String,
+        license: ModelLicense,
     ) -> Result<()> {
         let model = &mut ctx.accounts.derived_model;
         let parent_model = &ctx.accounts.parent_model;
         let authority = &ctx.accounts.authority;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
+        // An initialized PDA means this authority already registered the name
+        if model.authority != Pubkey::default() {
+            return Err(ErrorCode::ModelAlreadyRegistered.into());
+        }
+        
         // Validate inputs
-        if model_hash.len() < 16 {
-            return Err(ErrorCode::InvalidModelHash.into());
+        if name.is_empty() || name.len() > MAX_MODEL_NAME_LEN {
+            return Err(ErrorCode::InvalidModelName.into());
+        }
+        
+        if description.len() > DESCRIPTION_BUDGET ||
+           performance_metrics.len() > PERFORMANCE_METRICS_BUDGET {
+            return Err(ErrorCode::FieldTooLong.into());
+        }
+        
+        if !is_valid_performance_metrics(&performance_metrics) {
+            return Err(ErrorCode::InvalidMetricsFormat.into());
         }
         
-        if accuracy < 0.0 || accuracy > 1.0 {
+        validate_sha256(&model_hash).map_err(|_| ErrorCode::InvalidModelHash)?;
+        
+        if accuracy > BPS_DENOMINATOR {
             return Err(ErrorCode::InvalidAccuracyValue.into());
         }
         
+        if !is_valid_storage_uri(&storage_uri) {
+            return Err(ErrorCode::InvalidStorageUri.into());
+        }
+        
+        check_model_type(&ctx.accounts.category_registry, &model_type, accuracy)?;
+        
+        // A derived model must not reuse its parent's artifact
+        if model_hash == parent_model.model_hash {
+            return Err(ErrorCode::DuplicateModelHash.into());
+        }
+        
+        // Only the parent's authority may derive from a proprietary model, and
+        // a derivative may not loosen the parent's license
+        if parent_model.license == ModelLicense::Proprietary
+            && authority.key() != parent_model.authority {
+            return Err(ErrorCode::LicenseViolation.into());
+        }
+        if !parent_model.license.permits_derived(&license) {
+            return Err(ErrorCode::LicenseViolation.into());
+        }
+        
+        // Bound the derivation chain
+        let lineage_depth = parent_model.lineage_depth
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        if lineage_depth > MAX_LINEAGE_DEPTH {
+            return Err(ErrorCode::LineageTooDeep.into());
+        }
+        
         // Initialize model
         model.name = name;
         model.description = description;
@@ -256,14 +1908,57 @@ pub mod model_operations {
         model.accuracy = accuracy;
         model.performance_metrics = performance_metrics;
         model.authority = authority.key();
+        model.creator = authority.key();
         model.created_at = current_timestamp;
         model.updated_at = current_timestamp;
         model.contribution_count = 0;
         model.verification_count = 0;
-        model.avg_confidence_score = 0.0;
+        model.avg_confidence_score = 0;
         model.usage_count = 0;
         model.is_verified = false;
         model.parent_model = Some(parent_model.key());
+        model.lineage_depth = lineage_depth;
+        model.bump = *ctx.bumps.get("derived_model").unwrap();
+        model.proposed_authority = None;
+        model.authority_proposal_timestamp = 0;
+        model.authority_proposal_window = 0;
+        model.status = ModelStatus::Active;
+        model.tags = Vec::new();
+        model.usage_fee = 0;
+        model.total_fees_collected = 0;
+        model.storage_uri = storage_uri;
+        model.computed_accuracy = 0;
+        model.accuracy_contribution_count = 0;
+        model.base_reward = 0;
+        model.accuracy_multiplier = 0;
+        model.max_reward = 0;
+        model.total_rewards_distributed = 0;
+        model.verification_fee = 0;
+        model.require_contribution_verification = false;
+        model.average_rating = 0;
+        model.rating_count = 0;
+        model.total_confidence_weight = 0;
+        model.authorized_verifiers = Vec::new();
+        model.usage_quota = 0;
+        model.usage_period_seconds = 0;
+        model.usage_period_bucket = 0;
+        model.period_usage_count = 0;
+        model.license = license;
+        model.parent_royalty_bps = 0;
+        model.confidence_half_life_seconds = 0;
+        model.verification_challenge_period = 0;
+        model.reward_escrow_period = 0;
+        model.auto_approve_threshold = None;
+        model.auto_approve_reward = 0;
+        model.last_confidence_update = current_timestamp;
+        model.approved_contribution_count = 0;
+        model.unique_contributor_count = 0;
+        
+        // Assign the next sequential index
+        model.model_index = ctx.accounts.model_counter.next_index()?;
+        let index_entry = &mut ctx.accounts.model_index;
+        index_entry.model = model.key();
+        index_entry.bump = *ctx.bumps.get("model_index").unwrap();
         
         msg!("Derived model created: {} v{} from parent {}", 
              model.name, model.version, parent_model.key());
@@ -271,6 +1966,87 @@ pub mod model_operations {
     }
 }
 
+/// Context for proposing a model ownership transfer
+#[derive(Accounts)]
+pub struct ProposeModelTransfer<'info> {
+    /// Model to transfer
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Current model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for accepting a model ownership transfer
+#[derive(Accounts)]
+pub struct AcceptModelTransfer<'info> {
+    /// Model being transferred
+    #[account(mut)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// New authority accepting the transfer
+    pub new_authority: Signer<'info>,
+}
+
+/// Context for setting a model's lifecycle status
+#[derive(Accounts)]
+pub struct SetModelStatus<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for archiving a model
+#[derive(Accounts)]
+pub struct ArchiveModel<'info> {
+    /// Model to archive
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority (receives the rent if the model is closed)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Context for merging one model's statistics into another
+#[derive(Accounts)]
+pub struct MergeModels<'info> {
+    /// Surviving model receiving the statistics
+    #[account(
+        mut,
+        constraint = target_model.authority == target_authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub target_model: Account<'info, ModelRegistry>,
+    
+    /// Model being merged and archived
+    #[account(
+        mut,
+        constraint = source_model.key() != target_model.key() @ ErrorCode::ModelMergedIntoItself,
+        constraint = source_model.authority == source_authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub source_model: Account<'info, ModelRegistry>,
+    
+    /// Target model authority
+    pub target_authority: Signer<'info>,
+    
+    /// Source model authority
+    pub source_authority: Signer<'info>,
+}
+
+/// Context for adding or removing model tags
+#[derive(Accounts)]
+pub struct ManageModelTags<'info> {
+    /// Model to tag
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
 /// Context for verifying a model
 #[derive(Accounts)]
 pub struct VerifyModel<'info> {
@@ -281,8 +2057,346 @@ pub struct VerifyModel<'info> {
     /// Verifier with permission to verify models
     pub verifier: Signer<'info>,
     
-    /// The MDNX token (used to check if verifier has authority)
+    /// The canonical MDNX token (used to check if verifier has authority)
+    pub mdnx_token: Account<'info, crate::token::MdnxToken>,
+    
+    /// The global config naming the canonical MDNX token
+    #[account(
+        seeds = [crate::config::CONFIG_SEED],
+        bump = config.bump,
+        has_one = mdnx_token @ ErrorCode::InvalidTokenAccount
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Context for revoking a model's verification
+#[derive(Accounts)]
+pub struct UnverifyModel<'info> {
+    /// Model to unverify
+    #[account(mut)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Token authority revoking the verification
+    pub authority: Signer<'info>,
+    
+    /// The canonical MDNX token (used to check the authority)
     pub mdnx_token: Account<'info, crate::token::MdnxToken>,
+    
+    /// The global config naming the canonical MDNX token
+    #[account(
+        seeds = [crate::config::CONFIG_SEED],
+        bump = config.bump,
+        has_one = mdnx_token @ ErrorCode::InvalidTokenAccount
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Context for reading a model's statistics
+#[derive(Accounts)]
+pub struct GetModelStats<'info> {
+    /// Model to read
+    pub model_registry: Account<'info, ModelRegistry>,
+}
+
+/// Context for setting a model's usage fee
+#[derive(Accounts)]
+pub struct SetUsageFee<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a derived model's parent royalty
+#[derive(Accounts)]
+pub struct SetParentRoyalty<'info> {
+    /// Derived model paying the royalty
+    #[account(
+        mut,
+        constraint = derived_model.parent_model == Some(parent_model.key()) @ ErrorCode::ModelMismatch
+    )]
+    pub derived_model: Account<'info, ModelRegistry>,
+    
+    /// Parent model whose owner sets the royalty
+    #[account(has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub parent_model: Account<'info, ModelRegistry>,
+    
+    /// Parent model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a model's confidence half-life
+#[derive(Accounts)]
+pub struct SetConfidenceHalfLife<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a model's verification challenge period
+#[derive(Accounts)]
+pub struct SetVerificationChallengePeriod<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a model's reward escrow period
+#[derive(Accounts)]
+pub struct SetRewardEscrowPeriod<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a model's auto-approve policy
+#[derive(Accounts)]
+pub struct SetAutoApprovePolicy<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a model's usage quota
+#[derive(Accounts)]
+pub struct SetUsageQuota<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a model's verification fee
+#[derive(Accounts)]
+pub struct SetVerificationFee<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a model's verifier allowlist
+#[derive(Accounts)]
+pub struct SetModelVerifiers<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a model's contribution verification requirement
+#[derive(Accounts)]
+pub struct SetVerificationRequirement<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for creating the global usage leaderboard
+#[derive(Accounts)]
+pub struct InitLeaderboard<'info> {
+    /// The leaderboard PDA
+    #[account(
+        init,
+        payer = payer,
+        space = Leaderboard::LEN,
+        seeds = [LEADERBOARD_SEED],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+    
+    /// Payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for creating the global model counter
+#[derive(Accounts)]
+pub struct InitModelCounter<'info> {
+    /// The model counter PDA
+    #[account(
+        init,
+        payer = payer,
+        space = ModelRegistryCounter::LEN,
+        seeds = [MODEL_COUNTER_SEED],
+        bump
+    )]
+    pub model_counter: Account<'info, ModelRegistryCounter>,
+    
+    /// Payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for adding a model category
+#[derive(Accounts)]
+pub struct AddCategory<'info> {
+    /// The global category registry PDA
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CategoryRegistry::LEN,
+        seeds = [CATEGORY_REGISTRY_SEED],
+        bump
+    )]
+    pub category_registry: Account<'info, CategoryRegistry>,
+    
+    /// The global config naming the admin
+    #[account(
+        seeds = [crate::config::CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAccess
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Config admin (payer)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for setting a model type's minimum accuracy
+#[derive(Accounts)]
+pub struct SetCategoryMinAccuracy<'info> {
+    /// The global category registry PDA
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CategoryRegistry::LEN,
+        seeds = [CATEGORY_REGISTRY_SEED],
+        bump
+    )]
+    pub category_registry: Account<'info, CategoryRegistry>,
+    
+    /// The global config naming the admin
+    #[account(
+        seeds = [crate::config::CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAccess
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Config admin (payer)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for removing a model category
+#[derive(Accounts)]
+pub struct RemoveCategory<'info> {
+    /// The global category registry PDA
+    #[account(
+        mut,
+        seeds = [CATEGORY_REGISTRY_SEED],
+        bump = category_registry.bump
+    )]
+    pub category_registry: Account<'info, CategoryRegistry>,
+    
+    /// The global config naming the admin
+    #[account(
+        seeds = [crate::config::CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAccess
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Config admin
+    pub admin: Signer<'info>,
+}
+
+/// Context for rating a model
+#[derive(Accounts)]
+pub struct RateModel<'info> {
+    /// Model being rated
+    #[account(mut)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Rating PDA; its existence blocks a second rating from the same user
+    #[account(
+        init,
+        payer = rater,
+        space = Rating::LEN,
+        seeds = [RATING_SEED, model_registry.key().as_ref(), rater.key().as_ref()],
+        bump
+    )]
+    pub rating: Account<'info, Rating>,
+    
+    /// User submitting the rating (payer)
+    #[account(mut)]
+    pub rater: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for submitting a benchmark result
+#[derive(Accounts)]
+#[instruction(benchmark_name: String)]
+pub struct SubmitBenchmark<'info> {
+    /// Benchmarked model
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Benchmark PDA, one per model and benchmark name
+    #[account(
+        init,
+        payer = submitter,
+        space = Benchmark::LEN,
+        seeds = [BENCHMARK_SEED, model_registry.key().as_ref(), benchmark_name.as_bytes()],
+        bump
+    )]
+    pub benchmark: Account<'info, Benchmark>,
+    
+    /// Account submitting the result (payer)
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for setting a model's reward policy
+#[derive(Accounts)]
+pub struct SetRewardPolicy<'info> {
+    /// Model to update
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAccess)]
+    pub model_registry: Account<'info, ModelRegistry>,
+    
+    /// Model authority
+    pub authority: Signer<'info>,
 }
 
 /// Context for recording model usage
@@ -292,8 +2406,51 @@ pub struct RecordModelUsage<'info> {
     #[account(mut)]
     pub model_registry: Account<'info, ModelRegistry>,
     
+    /// The MDNX token (fees are paid in MDNX)
+    pub mdnx_token: Account<'info, crate::token::MdnxToken>,
+    
+    /// User's token account paying the usage fee
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = user_token_account.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    /// Model owner's token account receiving the usage fee
+    #[account(
+        mut,
+        constraint = fee_destination.owner == model_registry.authority @ ErrorCode::InvalidTokenAccount,
+        constraint = fee_destination.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub fee_destination: Account<'info, TokenAccount>,
+    
+    /// Verification of the model that must still be valid (optional)
+    #[account(
+        constraint = verification.model == Some(model_registry.key()) @ ErrorCode::ModelMismatch
+    )]
+    pub verification: Option<Account<'info, crate::verification::Verification>>,
+    
+    /// Parent of a derived model (required when a royalty is owed)
+    #[account(
+        constraint = model_registry.parent_model == Some(parent_model.key()) @ ErrorCode::ModelMismatch
+    )]
+    pub parent_model: Option<Account<'info, ModelRegistry>>,
+    
+    /// Parent owner's token account receiving the royalty (required when a royalty is owed)
+    #[account(mut)]
+    pub parent_fee_destination: Option<Account<'info, TokenAccount>>,
+    
+    /// Usage leaderboard, updated once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(mut, seeds = [LEADERBOARD_SEED], bump)]
+    pub leaderboard: UncheckedAccount<'info>,
+    
     /// User of the model
     pub user: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
 }
 
 /// Context for creating a derived model
@@ -306,8 +2463,14 @@ pub struct RecordModelUsage<'info> {
     model_hash: String
 )]
 pub struct CreateDerivedModel<'info> {
-    /// The new derived model
-    #[account(init, payer = authority, space = ModelRegistry::LEN)]
+    /// The new derived model PDA keyed by authority and name
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ModelRegistry::LEN,
+        seeds = [MODEL_SEED, authority.key().as_ref(), name.as_bytes()],
+        bump
+    )]
     pub derived_model: Account<'info, ModelRegistry>,
     
     /// The parent model
@@ -317,6 +2480,25 @@ pub struct CreateDerivedModel<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    /// Global model counter handing out the sequential index
+    #[account(mut, seeds = [MODEL_COUNTER_SEED], bump = model_counter.bump)]
+    pub model_counter: Account<'info, ModelRegistryCounter>,
+    
+    /// Index entry pointing at the new model
+    #[account(
+        init,
+        payer = authority,
+        space = ModelIndexEntry::LEN,
+        seeds = [MODEL_INDEX_SEED, &model_counter.model_count.to_le_bytes()],
+        bump
+    )]
+    pub model_index: Account<'info, ModelIndexEntry>,
+    
+    /// Registry of valid model types, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [CATEGORY_REGISTRY_SEED], bump)]
+    pub category_registry: UncheckedAccount<'info>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::token::MdnxToken;
+
+/// Seed for stake accounts
+pub const STAKE_SEED: &[u8] = b"stake";
+
+/// Seed for the stake vault token account
+pub const STAKE_VAULT_SEED: &[u8] = b"stake_vault";
+
+/// Stake account data structure
+#[account]
+pub struct StakeAccount {
+    /// Owner of the staked tokens
+    pub owner: Pubkey,
+    
+    /// The MDNX token being staked
+    pub mdnx_token: Pubkey,
+    
+    /// Amount of tokens currently staked
+    pub amount: u64,
+    
+    /// Tokens cannot be unstaked before this timestamp
+    pub locked_until: i64,
+    
+    /// Bump of the stake account PDA
+    pub bump: u8,
+}
+
+impl StakeAccount {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        32 + // mdnx_token
+        8 + // amount
+        8 + // locked_until
+        1; // bump
+}
+
+/// Staking operation implementations
+pub mod staking_operations {
+    use super::*;
+    
+    /// Lock MDNX tokens in the stake vault
+    pub fn stake_tokens(
+        ctx: Context<StakeTokens>,
+        amount: u64,
+        lock_duration: i64,
+    ) -> Result<()> {
+        let stake = &mut ctx.accounts.stake_account;
+        let mdnx_token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        if mdnx_token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        // Validate inputs
+        if amount == 0 || lock_duration < 0 {
+            return Err(ErrorCode::InvalidStakeAmount.into());
+        }
+        
+        if amount > ctx.accounts.owner_token_account.amount {
+            return Err(ErrorCode::InsufficientTokenBalance.into());
+        }
+        
+        // Transfer tokens from owner to vault
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        );
+        
+        token::transfer(transfer_ctx, amount)?;
+        
+        // Update stake
+        stake.owner = ctx.accounts.owner.key();
+        stake.mdnx_token = mdnx_token.key();
+        stake.bump = *ctx.bumps.get("stake_account").unwrap();
+        stake.amount = stake.amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let locked_until = current_timestamp
+            .checked_add(lock_duration)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        stake.locked_until = stake.locked_until.max(locked_until);
+        
+        mdnx_token.total_staked = mdnx_token.total_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        msg!("Staked {} MDNX tokens until {}", amount, stake.locked_until);
+        Ok(())
+    }
+    
+    /// Withdraw staked MDNX tokens after the lock expires
+    pub fn unstake_tokens(
+        ctx: Context<UnstakeTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        let stake = &mut ctx.accounts.stake_account;
+        let mdnx_token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        if mdnx_token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        // Reject withdrawals before the lock expires
+        if current_timestamp < stake.locked_until {
+            return Err(ErrorCode::StakeLocked.into());
+        }
+        
+        if amount == 0 || amount > stake.amount {
+            return Err(ErrorCode::InsufficientTokenBalance.into());
+        }
+        
+        // Transfer tokens from vault back to owner
+        let mdnx_token_key = mdnx_token.key();
+        let seeds = &[
+            STAKE_VAULT_SEED,
+            mdnx_token_key.as_ref(),
+            &[*ctx.bumps.get("stake_vault").unwrap()],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: ctx.accounts.stake_vault.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(transfer_ctx, amount)?;
+        
+        // Update stake
+        stake.amount = stake.amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        mdnx_token.total_staked = mdnx_token.total_staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        msg!("Unstaked {} MDNX tokens", amount);
+        Ok(())
+    }
+}
+
+/// Context for staking tokens
+#[derive(Accounts)]
+pub struct StakeTokens<'info> {
+    /// The owner's stake account
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = StakeAccount::LEN,
+        seeds = [STAKE_SEED, mdnx_token.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    /// Program-owned vault holding staked tokens
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [STAKE_VAULT_SEED, mdnx_token.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = stake_vault
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// The MDNX token
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Token mint
+    #[account(constraint = mint.key() == mdnx_token.mint @ ErrorCode::InvalidTokenAccount)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Owner's token account (source)
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    
+    /// Owner (payer)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+    
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for unstaking tokens
+#[derive(Accounts)]
+pub struct UnstakeTokens<'info> {
+    /// The owner's stake account
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, mdnx_token.key().as_ref(), owner.key().as_ref()],
+        bump = stake_account.bump,
+        has_one = owner @ ErrorCode::UnauthorizedAccess
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    /// Program-owned vault holding staked tokens
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, mdnx_token.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    /// The MDNX token
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Owner's token account (destination)
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    
+    /// Owner
+    pub owner: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+} 
\ No newline at end of file
@@ -1,7 +1,49 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::metadata;
+use mpl_token_metadata::state::DataV2;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, Token, TokenAccount};
 use crate::errors::ErrorCode;
 
+/// Seed for the MDNX token PDA
+pub const MDNX_TOKEN_SEED: &[u8] = b"mdnx_token";
+
+/// Default minimum delay between mints, in seconds
+pub const DEFAULT_MINT_COOLDOWN_SECONDS: i64 = 3600;
+
+/// Default window for accepting an authority transfer, in seconds
+pub const DEFAULT_AUTHORITY_TRANSFER_WINDOW: i64 = 86400;
+
+/// Maximum token name length (32 reserved bytes minus the 4-byte length prefix)
+pub const MAX_TOKEN_NAME_LEN: usize = 28;
+
+/// Maximum token symbol length (8 reserved bytes minus the 4-byte length prefix)
+pub const MAX_TOKEN_SYMBOL_LEN: usize = 4;
+
+/// Maximum token URI length (128 reserved bytes minus the 4-byte length prefix)
+pub const MAX_URI_LEN: usize = 124;
+
+/// Maximum authority proposal reason length (128 reserved bytes minus the 4-byte length prefix)
+pub const MAX_PROPOSAL_REASON_LEN: usize = 124;
+
+/// Maximum number of multisig signers
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// Maximum number of delegated minters
+pub const MAX_MINTERS: usize = 10;
+
+/// Maximum number of decimals for the MDNX mint
+pub const MAX_TOKEN_DECIMALS: u8 = 9;
+
+/// Maximum number of recipients in a batch mint
+pub const MAX_BATCH_RECIPIENTS: usize = 20;
+
+/// Seed for the mint authority PDA
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
+
+/// Seed for the program-owned treasury token account PDA
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
 /// MDNX Token data structure
 #[account]
 pub struct MdnxToken {
@@ -40,6 +82,73 @@ pub struct MdnxToken {
     
     /// Treasury account
     pub treasury: Pubkey,
+    
+    /// Maximum number of tokens that may ever be minted
+    pub max_supply: u64,
+    
+    /// Total tokens minted so far
+    pub minted_so_far: u64,
+    
+    /// Tokens currently in circulation (minted minus burned)
+    pub circulating_supply: u64,
+    
+    /// Minimum delay between mints, in seconds
+    pub mint_cooldown_seconds: i64,
+    
+    /// Multisig signers allowed to co-approve authority operations
+    pub signers: Vec<Pubkey>,
+    
+    /// Number of multisig signers required (0 disables multisig)
+    pub threshold: u8,
+    
+    /// Emergency pause flag halting mint, burn and transfer operations
+    pub is_paused: bool,
+    
+    /// Delegated minters allowed to mint alongside the authority
+    pub minters: Vec<Pubkey>,
+    
+    /// Bump of the mint authority PDA
+    pub mint_authority_bump: u8,
+    
+    /// Total tokens locked in staking
+    pub total_staked: u64,
+    
+    /// Bump of the MDNX token PDA
+    pub bump: u8,
+    
+    /// Default window for accepting an authority transfer, in seconds
+    pub authority_transfer_window: i64,
+    
+    /// Acceptance window of the pending authority proposal, in seconds
+    pub authority_proposal_window: i64,
+    
+    /// Minimum time after a proposal before it can be accepted, in seconds,
+    /// leaving the authority time to cancel a malicious transfer
+    pub min_transfer_delay_seconds: i64,
+    
+    /// Why the pending authority transfer was proposed, for auditors (empty if none given)
+    pub authority_proposal_reason: String,
+    
+    /// Decimals of the mint
+    pub decimals: u8,
+    
+    /// Bump of the program-owned treasury PDA
+    pub treasury_bump: u8,
+    
+    /// Largest single reward the program-owned treasury pays (0 pays none)
+    pub max_reward_payout: u64,
+    
+    /// Total rewards the program-owned treasury pays per budget period
+    pub reward_budget: u64,
+    
+    /// Length of a reward budget period, in seconds
+    pub reward_budget_period: i64,
+    
+    /// Start of the current reward budget period
+    pub reward_period_start: i64,
+    
+    /// Rewards paid from the program-owned treasury in the current period
+    pub reward_period_paid: u64,
 }
 
 impl MdnxToken {
@@ -55,7 +164,208 @@ impl MdnxToken {
         33 + // proposed_authority (Option<Pubkey>)
         8 + // authority_proposal_timestamp
         8 + // last_mint_timestamp
-        32; // treasury
+        32 + // treasury
+        8 + // max_supply
+        8 + // minted_so_far
+        8 + // circulating_supply
+        8 + // mint_cooldown_seconds
+        4 + 32 * MAX_MULTISIG_SIGNERS + // signers (Vec<Pubkey>)
+        1 + // threshold
+        1 + // is_paused
+        4 + 32 * MAX_MINTERS + // minters (Vec<Pubkey>)
+        1 + // mint_authority_bump
+        8 + // total_staked
+        1 + // bump
+        8 + // authority_transfer_window
+        8 + // authority_proposal_window
+        8 + // min_transfer_delay_seconds
+        128 + // authority_proposal_reason (string)
+        1 + // decimals
+        1 + // treasury_bump
+        8 + // max_reward_payout
+        8 + // reward_budget
+        8 + // reward_budget_period
+        8 + // reward_period_start
+        8; // reward_period_paid
+    
+    /// Verify the authority signed and, when multisig is enabled, that
+    /// enough recognized signers approved via remaining accounts
+    pub fn verify_authority(
+        &self,
+        authority: &AccountInfo,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        require_keys_eq!(
+            self.authority,
+            authority.key(),
+            ErrorCode::UnauthorizedAccess
+        );
+        
+        if self.threshold == 0 {
+            return Ok(());
+        }
+        
+        // Count distinct recognized signers, starting with the authority
+        let mut approvals: Vec<Pubkey> = Vec::new();
+        if authority.is_signer && self.signers.contains(authority.key) {
+            approvals.push(authority.key());
+        }
+        for account in remaining_accounts.iter() {
+            if account.is_signer
+                && self.signers.contains(account.key)
+                && !approvals.contains(account.key)
+            {
+                approvals.push(account.key());
+            }
+        }
+        
+        if approvals.len() < self.threshold as usize {
+            return Err(ErrorCode::UnauthorizedAccess.into());
+        }
+        
+        Ok(())
+    }
+    
+    /// Verify the signer may mint: a delegated minter, or the authority
+    /// (and multisig, if enabled)
+    pub fn verify_minter(
+        &self,
+        signer: &AccountInfo,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        if self.minters.contains(signer.key) {
+            return Ok(());
+        }
+        
+        if signer.key() != self.authority {
+            return Err(ErrorCode::NotAuthorizedMinter.into());
+        }
+        
+        self.verify_authority(signer, remaining_accounts)
+    }
+    
+    /// Check minting is not paused and the mint cooldown has elapsed
+    pub fn check_can_mint(&self, current_timestamp: i64) -> Result<()> {
+        if self.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        let time_since_last_mint = current_timestamp - self.last_mint_timestamp;
+        if time_since_last_mint < self.mint_cooldown_seconds && self.last_mint_timestamp > 0 {
+            return Err(ErrorCode::RateLimited.into());
+        }
+        
+        Ok(())
+    }
+    
+    /// Record minted tokens, enforcing the max supply cap
+    pub fn record_mint(&mut self, amount: u64) -> Result<()> {
+        let minted = self.minted_so_far
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        if minted > self.max_supply {
+            return Err(ErrorCode::SupplyCapExceeded.into());
+        }
+        
+        self.minted_so_far = minted;
+        Ok(())
+    }
+    
+    /// Add newly minted tokens to the circulating supply
+    pub fn record_circulation(&mut self, amount: u64) -> Result<()> {
+        self.circulating_supply = self.circulating_supply
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+    
+    /// Record a reward paid from the program-owned treasury, enforcing the
+    /// per-reward and per-period limits set by the token authority
+    pub fn record_reward_payout(&mut self, amount: u64, current_timestamp: i64) -> Result<()> {
+        if amount > self.max_reward_payout {
+            return Err(ErrorCode::TreasuryPayoutLimitExceeded.into());
+        }
+        
+        // Start a new budget period once the current one has elapsed
+        let elapsed = current_timestamp
+            .checked_sub(self.reward_period_start)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        if elapsed >= self.reward_budget_period {
+            self.reward_period_start = current_timestamp;
+            self.reward_period_paid = 0;
+        }
+        
+        let paid = self.reward_period_paid
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        if paid > self.reward_budget {
+            return Err(ErrorCode::TreasuryPayoutLimitExceeded.into());
+        }
+        
+        self.reward_period_paid = paid;
+        Ok(())
+    }
+    
+    /// Record burned tokens against the stored supply
+    pub fn record_burn(&mut self, amount: u64) -> Result<()> {
+        self.total_supply = self.total_supply
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.circulating_supply = self.circulating_supply
+            .checked_sub(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Whether a treasury is the program-owned PDA, which signs for itself
+pub fn is_program_treasury(treasury: &Account<TokenAccount>) -> bool {
+    treasury.owner == treasury.key()
+}
+
+/// Transfer tokens out of a treasury. The program-owned treasury PDA signs
+/// for itself; any other treasury account must be signed for by its owner.
+pub fn transfer_from_treasury<'info>(
+    token_program: AccountInfo<'info>,
+    treasury: &Account<'info, TokenAccount>,
+    owner: Option<AccountInfo<'info>>,
+    destination: AccountInfo<'info>,
+    treasury_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    if is_program_treasury(treasury) {
+        let seeds = &[
+            TREASURY_SEED,
+            treasury.mint.as_ref(),
+            &[treasury_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            token_program,
+            token::Transfer {
+                from: treasury.to_account_info(),
+                to: destination,
+                authority: treasury.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        return token::transfer(transfer_ctx, amount);
+    }
+    
+    let owner = owner.ok_or(ErrorCode::UnauthorizedAccess)?;
+    let transfer_ctx = CpiContext::new(
+        token_program,
+        token::Transfer {
+            from: treasury.to_account_info(),
+            to: destination,
+            authority: owner,
+        },
+    );
+    
+    token::transfer(transfer_ctx, amount)
 }
 
 /// Authority transfer state - used for two-step authority transfer
@@ -65,6 +375,34 @@ pub struct AuthorityTransferState {
     pub proposal_timestamp: i64,
 }
 
+/// A key's role on the token, returned by get_authority_status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct AuthorityStatus {
+    /// Whether the key is the current authority
+    pub is_authority: bool,
+    
+    /// Whether the key is the pending proposed authority
+    pub is_proposed_authority: bool,
+    
+    /// When the pending proposal stops being acceptable (None if no proposal is pending)
+    pub proposal_expires_at: Option<i64>,
+}
+
+/// Emitted when MDNX tokens are minted
+#[event]
+pub struct TokensMinted {
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when token authority changes hands
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
 /// Token operation implementations
 pub mod token_operations {
     use super::*;
@@ -76,16 +414,41 @@ pub mod token_operations {
         symbol: String,
         uri: String,
         total_supply: u64,
+        mint_cooldown_seconds: i64,
+        authority_transfer_window: i64,
+        min_transfer_delay_seconds: i64,
+        create_metadata: bool,
+        decimals: u8,
     ) -> Result<()> {
         let token = &mut ctx.accounts.mdnx_token;
         let authority = &ctx.accounts.authority;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
         // Validate inputs
+        if name.len() > MAX_TOKEN_NAME_LEN || symbol.len() > MAX_TOKEN_SYMBOL_LEN || uri.len() > MAX_URI_LEN {
+            return Err(ErrorCode::FieldTooLong.into());
+        }
+        
         if total_supply == 0 {
             return Err(ErrorCode::InvalidTokenSupply.into());
         }
         
+        if mint_cooldown_seconds < 0 {
+            return Err(ErrorCode::InvalidMintCooldown.into());
+        }
+        
+        if authority_transfer_window < 0 {
+            return Err(ErrorCode::InvalidTransferWindow.into());
+        }
+        
+        if min_transfer_delay_seconds < 0 {
+            return Err(ErrorCode::InvalidTransferWindow.into());
+        }
+        
+        if decimals > MAX_TOKEN_DECIMALS {
+            return Err(ErrorCode::InvalidTokenDecimals.into());
+        }
+        
         // Initialize token
         token.name = name;
         token.symbol = symbol;
@@ -98,7 +461,91 @@ pub mod token_operations {
         token.proposed_authority = None;
         token.authority_proposal_timestamp = 0;
         token.last_mint_timestamp = 0;
-        token.treasury = authority.key(); // Initially set treasury to authority
+        token.treasury = ctx.accounts.treasury.key();
+        token.treasury_bump = *ctx.bumps.get("treasury").unwrap();
+        token.max_supply = total_supply;
+        token.minted_so_far = 0;
+        token.circulating_supply = 0;
+        token.mint_cooldown_seconds = if mint_cooldown_seconds == 0 {
+            ctx.accounts.config
+                .as_ref()
+                .map_or(DEFAULT_MINT_COOLDOWN_SECONDS, |config| config.default_mint_cooldown)
+        } else {
+            mint_cooldown_seconds
+        };
+        token.signers = Vec::new();
+        token.threshold = 0;
+        token.is_paused = false;
+        token.minters = Vec::new();
+        token.mint_authority_bump = *ctx.bumps.get("mint_authority").unwrap();
+        token.total_staked = 0;
+        token.bump = *ctx.bumps.get("mdnx_token").unwrap();
+        token.authority_transfer_window = if authority_transfer_window == 0 {
+            DEFAULT_AUTHORITY_TRANSFER_WINDOW
+        } else {
+            authority_transfer_window
+        };
+        token.authority_proposal_window = 0;
+        token.min_transfer_delay_seconds = min_transfer_delay_seconds;
+        token.authority_proposal_reason = String::new();
+        token.decimals = decimals;
+        token.max_reward_payout = 0;
+        token.reward_budget = 0;
+        token.reward_budget_period = 0;
+        token.reward_period_start = 0;
+        token.reward_period_paid = 0;
+        
+        // A transfer must stay acceptable for some time after the delay
+        if token.min_transfer_delay_seconds >= token.authority_transfer_window {
+            return Err(ErrorCode::InvalidTransferWindow.into());
+        }
+        
+        // Create Metaplex metadata so wallets and explorers can display the token
+        if create_metadata {
+            let metadata_account = ctx.accounts.metadata
+                .as_ref()
+                .ok_or(ErrorCode::MissingMetadataAccounts)?;
+            let metadata_program = ctx.accounts.token_metadata_program
+                .as_ref()
+                .ok_or(ErrorCode::MissingMetadataAccounts)?;
+            
+            let mint_key = ctx.accounts.mint.key();
+            let seeds = &[
+                MINT_AUTHORITY_SEED,
+                mint_key.as_ref(),
+                &[token.mint_authority_bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+            
+            let cpi_accounts = metadata::CreateMetadataAccountsV3 {
+                metadata: metadata_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                payer: authority.to_account_info(),
+                update_authority: authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            };
+            let cpi_context = CpiContext::new_with_signer(
+                metadata_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            
+            let data = DataV2 {
+                name: token.name.clone(),
+                symbol: token.symbol.clone(),
+                uri: token.uri.clone(),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            };
+            
+            metadata::create_metadata_accounts_v3(cpi_context, data, true, true, None)?;
+            
+            msg!("Token metadata created at {}", metadata_account.key());
+        }
         
         msg!("MDNX token initialized with supply: {}", total_supply);
         Ok(())
@@ -108,20 +555,43 @@ pub mod token_operations {
     pub fn propose_authority_transfer(
         ctx: Context<ProposeAuthorityTransfer>,
         new_authority: Pubkey,
+        window: Option<i64>,
+        reason: Option<String>,
     ) -> Result<()> {
         let token = &mut ctx.accounts.mdnx_token;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
-        // Only current authority can propose a transfer
-        require_keys_eq!(
-            token.authority,
-            ctx.accounts.authority.key(),
-            ErrorCode::UnauthorizedAccess
-        );
+        // Only current authority (and multisig, if enabled) can propose a transfer
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        // Proposing the current authority or the default key is meaningless
+        if new_authority == token.authority {
+            return Err(ErrorCode::SameAuthorityProposed.into());
+        }
+        
+        if new_authority == Pubkey::default() {
+            return Err(ErrorCode::InvalidProposedAuthority.into());
+        }
+        
+        // Use the per-proposal window if provided, otherwise the token default
+        let window = window.unwrap_or(token.authority_transfer_window);
+        if window <= token.min_transfer_delay_seconds {
+            return Err(ErrorCode::InvalidTransferWindow.into());
+        }
+        
+        let reason = reason.unwrap_or_default();
+        if reason.len() > MAX_PROPOSAL_REASON_LEN {
+            return Err(ErrorCode::FieldTooLong.into());
+        }
         
         // Set proposed authority
         token.proposed_authority = Some(new_authority);
         token.authority_proposal_timestamp = current_timestamp;
+        token.authority_proposal_window = window;
+        token.authority_proposal_reason = reason;
         token.last_update_timestamp = current_timestamp;
         
         msg!("Authority transfer proposed to: {}", new_authority);
@@ -146,18 +616,30 @@ pub mod token_operations {
             ErrorCode::UnauthorizedAccess
         );
         
-        // Check if proposal hasn't expired (24 hour window)
+        // Check the timelock has elapsed and the proposal hasn't expired
         let proposal_age = current_timestamp - token.authority_proposal_timestamp;
-        if proposal_age > 86400 { // 24 hours in seconds
+        if proposal_age < token.min_transfer_delay_seconds {
+            return Err(ErrorCode::TransferDelayNotElapsed.into());
+        }
+        
+        if proposal_age > token.authority_proposal_window {
             return Err(ErrorCode::AuthorityTransferExpired.into());
         }
         
         // Transfer authority
+        let old_authority = token.authority;
         token.authority = proposed_authority;
         token.proposed_authority = None;
         token.authority_proposal_timestamp = 0;
+        token.authority_proposal_window = 0;
+        token.authority_proposal_reason = String::new();
         token.last_update_timestamp = current_timestamp;
         
+        emit!(AuthorityTransferred {
+            old_authority,
+            new_authority: proposed_authority,
+        });
+        
         msg!("Authority transfer accepted, new authority: {}", proposed_authority);
         Ok(())
     }
@@ -169,22 +651,80 @@ pub mod token_operations {
         let token = &mut ctx.accounts.mdnx_token;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
-        // Only current authority can cancel a transfer
-        require_keys_eq!(
-            token.authority,
-            ctx.accounts.authority.key(),
-            ErrorCode::UnauthorizedAccess
-        );
+        // Only current authority (and multisig, if enabled) can cancel a transfer
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
         
         // Cancel proposed authority
         token.proposed_authority = None;
         token.authority_proposal_timestamp = 0;
+        token.authority_proposal_window = 0;
+        token.authority_proposal_reason = String::new();
         token.last_update_timestamp = current_timestamp;
         
         msg!("Authority transfer cancelled");
         Ok(())
     }
     
+    /// Clear an authority transfer proposal whose acceptance window has
+    /// passed; callable by anyone so keepers can tidy stale state
+    pub fn clear_expired_proposal(
+        ctx: Context<ClearExpiredProposal>,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only an existing, expired proposal can be cleared
+        let proposed_authority = token.proposed_authority
+            .ok_or(ErrorCode::InvalidAuthorityTransferState)?;
+        
+        let proposal_age = current_timestamp - token.authority_proposal_timestamp;
+        if proposal_age <= token.authority_proposal_window {
+            return Err(ErrorCode::InvalidAuthorityTransferState.into());
+        }
+        
+        // Clear proposed authority
+        token.proposed_authority = None;
+        token.authority_proposal_timestamp = 0;
+        token.authority_proposal_window = 0;
+        token.authority_proposal_reason = String::new();
+        token.last_update_timestamp = current_timestamp;
+        
+        msg!("Expired authority transfer proposal to {} cleared", proposed_authority);
+        Ok(())
+    }
+    
+    /// Publish whether a key is the current or proposed authority as return
+    /// data so clients can gate role-specific actions without decoding the token
+    pub fn get_authority_status(
+        ctx: Context<GetAuthorityStatus>,
+        account: Pubkey,
+    ) -> Result<()> {
+        let token = &ctx.accounts.mdnx_token;
+        
+        let proposal_expires_at = if token.proposed_authority.is_some() {
+            Some(
+                token.authority_proposal_timestamp
+                    .checked_add(token.authority_proposal_window)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?
+            )
+        } else {
+            None
+        };
+        
+        let status = AuthorityStatus {
+            is_authority: token.authority == account,
+            is_proposed_authority: token.proposed_authority == Some(account),
+            proposal_expires_at,
+        };
+        anchor_lang::solana_program::program::set_return_data(&status.try_to_vec()?);
+        
+        msg!("Authority status for {}: authority {}, proposed {}", account, status.is_authority, status.is_proposed_authority);
+        Ok(())
+    }
+    
     /// Mint MDNX tokens
     pub fn mint_tokens(
         ctx: Context<MintTokens>,
@@ -193,39 +733,170 @@ pub mod token_operations {
         let token = &mut ctx.accounts.mdnx_token;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
-        // Only authority can mint
-        require_keys_eq!(
-            token.authority,
-            ctx.accounts.authority.key(),
-            ErrorCode::UnauthorizedAccess
-        );
+        // Delegated minters, or the authority (and multisig, if enabled), can mint
+        token.verify_minter(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
         
-        // Check rate limiting (minimum 1 hour between mints)
-        let time_since_last_mint = current_timestamp - token.last_mint_timestamp;
-        if time_since_last_mint < 3600 && token.last_mint_timestamp > 0 { // 1 hour in seconds
-            return Err(ErrorCode::RateLimited.into());
-        }
+        // Check pause state and rate limiting (minimum cooldown between mints)
+        token.check_can_mint(current_timestamp)?;
+        
+        // Enforce the supply cap before minting
+        token.record_mint(amount)?;
+        
+        // Mint tokens, signing with the mint authority PDA
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[token.mint_authority_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
         
-        // Mint tokens
         let cpi_accounts = token::MintTo {
             mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.destination.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
         };
         
         let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
         
         token::mint_to(cpi_context, amount)?;
         
         // Update token state
+        token.record_circulation(amount)?;
         token.last_mint_timestamp = current_timestamp;
         token.last_update_timestamp = current_timestamp;
         
+        emit!(TokensMinted {
+            amount,
+            destination: ctx.accounts.destination.key(),
+            timestamp: current_timestamp,
+        });
+        
         msg!("Minted {} MDNX tokens to {}", amount, ctx.accounts.destination.key());
         Ok(())
     }
     
+    /// Mint MDNX tokens to many recipients in one transaction
+    pub fn mint_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintBatch<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Destinations come first in remaining accounts, followed by any
+        // multisig co-signers
+        if amounts.is_empty()
+            || amounts.len() > MAX_BATCH_RECIPIENTS
+            || ctx.remaining_accounts.len() < amounts.len()
+        {
+            return Err(ErrorCode::InvalidBatchSize.into());
+        }
+        let (destinations, cosigners) = ctx.remaining_accounts.split_at(amounts.len());
+        
+        token.verify_minter(&ctx.accounts.authority.to_account_info(), cosigners)?;
+        
+        // Pause state and rate limit apply once for the whole batch
+        token.check_can_mint(current_timestamp)?;
+        
+        // Enforce the supply cap across the whole batch before minting any
+        let total = amounts
+            .iter()
+            .try_fold(0u64, |sum, amount| sum.checked_add(*amount))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        token.record_mint(total)?;
+        
+        // Mint to each recipient, signing with the mint authority PDA
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            mint_key.as_ref(),
+            &[token.mint_authority_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        for (destination, amount) in destinations.iter().zip(amounts.iter()) {
+            let destination_account = Account::<TokenAccount>::try_from(destination)?;
+            if destination_account.mint != token.mint {
+                return Err(ErrorCode::InvalidTokenAccount.into());
+            }
+            
+            let cpi_accounts = token::MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: destination.clone(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            };
+            let cpi_context = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            
+            token::mint_to(cpi_context, *amount)?;
+            
+            emit!(TokensMinted {
+                amount: *amount,
+                destination: destination.key(),
+                timestamp: current_timestamp,
+            });
+        }
+        
+        // Update token state
+        token.record_circulation(total)?;
+        token.last_mint_timestamp = current_timestamp;
+        token.last_update_timestamp = current_timestamp;
+        
+        msg!("Batch minted {} MDNX tokens to {} recipients", total, amounts.len());
+        Ok(())
+    }
+    
+    /// Burn MDNX tokens
+    pub fn burn_tokens(
+        ctx: Context<BurnTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only authority (and multisig, if enabled) can burn
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        if token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        // Ensure the source holds enough tokens
+        if amount > ctx.accounts.source.amount {
+            return Err(ErrorCode::InsufficientTokenBalance.into());
+        }
+        
+        // Burn tokens
+        let cpi_accounts = token::Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.source.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        
+        token::burn(cpi_context, amount)?;
+        
+        // Update token state
+        token.record_burn(amount)?;
+        token.last_update_timestamp = current_timestamp;
+        
+        msg!("Burned {} MDNX tokens from {}", amount, ctx.accounts.source.key());
+        Ok(())
+    }
+    
     /// Set treasury account
     pub fn set_treasury(
         ctx: Context<SetTreasury>,
@@ -234,18 +905,378 @@ pub mod token_operations {
         let token = &mut ctx.accounts.mdnx_token;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
-        // Only authority can set treasury
+        // Only authority (and multisig, if enabled) can set treasury
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        // Update treasury
+        token.treasury = new_treasury;
+        token.last_update_timestamp = current_timestamp;
+        
+        msg!("Treasury updated to: {}", new_treasury);
+        Ok(())
+    }
+    
+    /// Limit rewards paid from the program-owned treasury: no single reward
+    /// above `max_reward_payout`, and at most `reward_budget` per
+    /// `reward_budget_period` seconds (all zero stops reward payouts)
+    pub fn set_treasury_reward_limits(
+        ctx: Context<SetTreasuryRewardLimits>,
+        max_reward_payout: u64,
+        reward_budget: u64,
+        reward_budget_period: i64,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only authority (and multisig, if enabled) can open the treasury to rewards
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        // Validate inputs
+        let disabled = max_reward_payout == 0 && reward_budget == 0 && reward_budget_period == 0;
+        if !disabled && (max_reward_payout == 0
+            || reward_budget < max_reward_payout
+            || reward_budget_period <= 0)
+        {
+            return Err(ErrorCode::InvalidTreasuryRewardLimits.into());
+        }
+        
+        token.max_reward_payout = max_reward_payout;
+        token.reward_budget = reward_budget;
+        token.reward_budget_period = reward_budget_period;
+        token.reward_period_start = current_timestamp;
+        token.reward_period_paid = 0;
+        token.last_update_timestamp = current_timestamp;
+        
+        msg!(
+            "Treasury reward limits set: {} per reward, {} per {}s",
+            max_reward_payout, reward_budget, reward_budget_period
+        );
+        Ok(())
+    }
+    
+    /// Move MDNX out of the treasury, e.g. when migrating to a new treasury
+    pub fn sweep_treasury(
+        ctx: Context<SweepTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        let token = &ctx.accounts.mdnx_token;
+        
+        // Only authority (and multisig, if enabled) can sweep the treasury
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        if token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        // Ensure the treasury holds enough tokens
+        if amount > ctx.accounts.treasury.amount {
+            return Err(ErrorCode::InsufficientTokenBalance.into());
+        }
+        
+        // Transfer tokens
+        transfer_from_treasury(
+            ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury,
+            Some(ctx.accounts.authority.to_account_info()),
+            ctx.accounts.destination.to_account_info(),
+            token.treasury_bump,
+            amount,
+        )?;
+        
+        msg!("Swept {} MDNX tokens from treasury to {}", amount, ctx.accounts.destination.key());
+        Ok(())
+    }
+    
+    /// Set the minimum delay between mints
+    pub fn set_mint_cooldown(
+        ctx: Context<SetMintCooldown>,
+        mint_cooldown_seconds: i64,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only authority (and multisig, if enabled) can change the cooldown
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        if mint_cooldown_seconds < 0 {
+            return Err(ErrorCode::InvalidMintCooldown.into());
+        }
+        
+        // Update cooldown
+        token.mint_cooldown_seconds = mint_cooldown_seconds;
+        token.last_update_timestamp = current_timestamp;
+        
+        msg!("Mint cooldown updated to: {} seconds", mint_cooldown_seconds);
+        Ok(())
+    }
+    
+    /// Configure the M-of-N multisig for authority operations
+    pub fn set_multisig(
+        ctx: Context<SetMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Changing the multisig requires the existing multisig approval
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        // Validate configuration
+        if signers.len() > MAX_MULTISIG_SIGNERS || threshold as usize > signers.len() {
+            return Err(ErrorCode::InvalidMultisigConfig.into());
+        }
+        
+        if threshold == 0 && !signers.is_empty() {
+            return Err(ErrorCode::InvalidMultisigConfig.into());
+        }
+        
+        for (i, signer) in signers.iter().enumerate() {
+            if signers[..i].contains(signer) {
+                return Err(ErrorCode::InvalidMultisigConfig.into());
+            }
+        }
+        
+        // Update multisig
+        token.signers = signers;
+        token.threshold = threshold;
+        token.last_update_timestamp = current_timestamp;
+        
+        msg!("Multisig updated: {} of {} signers", threshold, token.signers.len());
+        Ok(())
+    }
+    
+    /// Update the token metadata URI
+    pub fn update_token_uri(
+        ctx: Context<UpdateTokenUri>,
+        new_uri: String,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only authority (and multisig, if enabled) can update the URI
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        // Validate URI
+        if new_uri.is_empty() || new_uri.len() > MAX_URI_LEN {
+            return Err(ErrorCode::InvalidTokenUri.into());
+        }
+        
+        token.uri = new_uri;
+        token.last_update_timestamp = current_timestamp;
+        
+        // Keep Metaplex metadata in sync when it is provided
+        if let (Some(metadata_account), Some(metadata_program)) = (
+            ctx.accounts.metadata.as_ref(),
+            ctx.accounts.token_metadata_program.as_ref(),
+        ) {
+            let cpi_accounts = metadata::UpdateMetadataAccountsV2 {
+                metadata: metadata_account.to_account_info(),
+                update_authority: ctx.accounts.authority.to_account_info(),
+            };
+            let cpi_context = CpiContext::new(metadata_program.to_account_info(), cpi_accounts);
+            
+            let data = DataV2 {
+                name: token.name.clone(),
+                symbol: token.symbol.clone(),
+                uri: token.uri.clone(),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            };
+            
+            metadata::update_metadata_accounts_v2(cpi_context, None, Some(data), None, None)?;
+        }
+        
+        msg!("Token URI updated to: {}", token.uri);
+        Ok(())
+    }
+    
+    /// Pause mint, burn and transfer operations
+    pub fn pause(
+        ctx: Context<SetPaused>,
+    ) -> Result<()> {
+        set_paused(ctx, true)
+    }
+    
+    /// Resume mint, burn and transfer operations
+    pub fn unpause(
+        ctx: Context<SetPaused>,
+    ) -> Result<()> {
+        set_paused(ctx, false)
+    }
+    
+    fn set_paused(
+        ctx: Context<SetPaused>,
+        is_paused: bool,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only authority (and multisig, if enabled) can pause
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        token.is_paused = is_paused;
+        token.last_update_timestamp = current_timestamp;
+        
+        msg!("Token paused: {}", is_paused);
+        Ok(())
+    }
+    
+    /// Freeze a holder's MDNX token account
+    pub fn freeze_token_account(
+        ctx: Context<FreezeTokenAccount>,
+    ) -> Result<()> {
+        set_frozen(ctx, true)
+    }
+    
+    /// Thaw a frozen MDNX token account
+    pub fn thaw_token_account(
+        ctx: Context<FreezeTokenAccount>,
+    ) -> Result<()> {
+        set_frozen(ctx, false)
+    }
+    
+    fn set_frozen(
+        ctx: Context<FreezeTokenAccount>,
+        frozen: bool,
+    ) -> Result<()> {
+        let token = &ctx.accounts.mdnx_token;
+        
+        // Only authority (and multisig, if enabled) can freeze or thaw accounts
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        // The mint's freeze authority must be the token authority
+        if ctx.accounts.mint.freeze_authority != COption::Some(token.authority) {
+            return Err(ErrorCode::FreezeAuthorityMismatch.into());
+        }
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        if frozen {
+            let cpi_accounts = token::FreezeAccount {
+                account: ctx.accounts.token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            };
+            token::freeze_account(CpiContext::new(cpi_program, cpi_accounts))?;
+        } else {
+            let cpi_accounts = token::ThawAccount {
+                account: ctx.accounts.token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            };
+            token::thaw_account(CpiContext::new(cpi_program, cpi_accounts))?;
+        }
+        
+        msg!("Token account {} frozen: {}", ctx.accounts.token_account.key(), frozen);
+        Ok(())
+    }
+    
+    /// Hand the mint's freeze authority to a new key
+    pub fn set_freeze_authority(
+        ctx: Context<SetFreezeAuthority>,
+        new_freeze_authority: Pubkey,
+    ) -> Result<()> {
+        // Only the current freeze authority can hand it on, which also lets
+        // a former token authority pass it to its successor
+        let current_freeze_authority = match ctx.accounts.mint.freeze_authority {
+            COption::Some(key) => key,
+            COption::None => return Err(ErrorCode::FreezeAuthorityMismatch.into()),
+        };
         require_keys_eq!(
-            token.authority,
             ctx.accounts.authority.key(),
+            current_freeze_authority,
             ErrorCode::UnauthorizedAccess
         );
         
-        // Update treasury
-        token.treasury = new_treasury;
+        let cpi_accounts = token::SetAuthority {
+            current_authority: ctx.accounts.authority.to_account_info(),
+            account_or_mint: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::set_authority(
+            CpiContext::new(cpi_program, cpi_accounts),
+            AuthorityType::FreezeAccount,
+            Some(new_freeze_authority),
+        )?;
+        
+        msg!("Freeze authority updated to: {}", new_freeze_authority);
+        Ok(())
+    }
+    
+    /// Add a delegated minter
+    pub fn add_minter(
+        ctx: Context<ManageMinters>,
+        minter: Pubkey,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only authority (and multisig, if enabled) can manage minters
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        if token.minters.contains(&minter) || token.minters.len() >= MAX_MINTERS {
+            return Err(ErrorCode::InvalidMinterList.into());
+        }
+        
+        token.minters.push(minter);
         token.last_update_timestamp = current_timestamp;
         
-        msg!("Treasury updated to: {}", new_treasury);
+        msg!("Minter added: {}", minter);
+        Ok(())
+    }
+    
+    /// Remove a delegated minter
+    pub fn remove_minter(
+        ctx: Context<ManageMinters>,
+        minter: Pubkey,
+    ) -> Result<()> {
+        let token = &mut ctx.accounts.mdnx_token;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only authority (and multisig, if enabled) can manage minters
+        token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        let index = token.minters
+            .iter()
+            .position(|existing| *existing == minter)
+            .ok_or(ErrorCode::NotAuthorizedMinter)?;
+        
+        token.minters.remove(index);
+        token.last_update_timestamp = current_timestamp;
+        
+        msg!("Minter removed: {}", minter);
         Ok(())
     }
 }
@@ -283,6 +1314,21 @@ pub struct CancelAuthorityTransfer<'info> {
     pub authority: Signer<'info>,
 }
 
+/// Context for clearing an expired authority transfer proposal
+#[derive(Accounts)]
+pub struct ClearExpiredProposal<'info> {
+    /// The MDNX token
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+}
+
+/// Context for reading a key's authority status
+#[derive(Accounts)]
+pub struct GetAuthorityStatus<'info> {
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+}
+
 /// Context for minting tokens
 #[derive(Accounts)]
 pub struct MintTokens<'info> {
@@ -291,12 +1337,77 @@ pub struct MintTokens<'info> {
     pub mdnx_token: Account<'info, MdnxToken>,
     
     /// Token mint
+    #[account(
+        mut,
+        constraint = mint.key() == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// Mint authority PDA
+    /// CHECK: PDA only used as the signer for the mint CPI
+    #[account(seeds = [MINT_AUTHORITY_SEED, mint.key().as_ref()], bump = mdnx_token.mint_authority_bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Destination account, which must hold the MDNX mint
+    #[account(
+        mut,
+        constraint = destination.mint == mint.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    /// Authority or delegated minter
+    pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for minting tokens to many recipients
+#[derive(Accounts)]
+pub struct MintBatch<'info> {
+    /// The MDNX token
     #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Token mint
+    #[account(
+        mut,
+        constraint = mint.key() == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
     pub mint: Account<'info, Mint>,
     
-    /// Destination account
+    /// Mint authority PDA
+    /// CHECK: PDA only used as the signer for the mint CPI
+    #[account(seeds = [MINT_AUTHORITY_SEED, mint.key().as_ref()], bump = mdnx_token.mint_authority_bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// Authority or delegated minter
+    pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for burning tokens
+#[derive(Accounts)]
+pub struct BurnTokens<'info> {
+    /// The MDNX token
     #[account(mut)]
-    pub destination: Account<'info, TokenAccount>,
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Token mint
+    #[account(
+        mut,
+        constraint = mint.key() == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// Source account to burn from, which must hold the MDNX mint
+    #[account(
+        mut,
+        constraint = source.mint == mint.key() @ ErrorCode::InvalidTokenAccount
+    )]
+    pub source: Account<'info, TokenAccount>,
     
     /// Authority
     pub authority: Signer<'info>,
@@ -312,6 +1423,148 @@ pub struct SetTreasury<'info> {
     #[account(mut)]
     pub mdnx_token: Account<'info, MdnxToken>,
     
+    /// Authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting the treasury reward limits
+#[derive(Accounts)]
+pub struct SetTreasuryRewardLimits<'info> {
+    /// The MDNX token
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for sweeping tokens out of the treasury
+#[derive(Accounts)]
+pub struct SweepTreasury<'info> {
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Treasury token account to sweep from
+    #[account(
+        mut,
+        constraint = treasury.key() == mdnx_token.treasury @ ErrorCode::InvalidTokenAccount
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Token account receiving the swept tokens
+    #[account(
+        mut,
+        constraint = destination.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    /// Authority (signs for the treasury unless it is the program-owned PDA)
+    pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for setting the mint cooldown
+#[derive(Accounts)]
+pub struct SetMintCooldown<'info> {
+    /// The MDNX token
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for configuring the multisig
+#[derive(Accounts)]
+pub struct SetMultisig<'info> {
+    /// The MDNX token
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for updating the token URI
+#[derive(Accounts)]
+pub struct UpdateTokenUri<'info> {
+    /// The MDNX token
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Authority (also the metadata update authority)
+    pub authority: Signer<'info>,
+    
+    /// Metaplex metadata account for the mint (optional)
+    /// CHECK: PDA validated by the token metadata program
+    #[account(mut)]
+    pub metadata: Option<UncheckedAccount<'info>>,
+    
+    /// Metaplex token metadata program (optional)
+    pub token_metadata_program: Option<Program<'info, metadata::Metadata>>,
+}
+
+/// Context for pausing or unpausing the token
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// The MDNX token
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for freezing or thawing a token account
+#[derive(Accounts)]
+pub struct FreezeTokenAccount<'info> {
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Token mint
+    #[account(constraint = mint.key() == mdnx_token.mint @ ErrorCode::InvalidTokenAccount)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Token account to freeze or thaw
+    #[account(
+        mut,
+        constraint = token_account.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+    
+    /// Authority (the mint's freeze authority)
+    pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for transferring the mint's freeze authority
+#[derive(Accounts)]
+pub struct SetFreezeAuthority<'info> {
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Token mint
+    #[account(mut, constraint = mint.key() == mdnx_token.mint @ ErrorCode::InvalidTokenAccount)]
+    pub mint: Account<'info, Mint>,
+    
+    /// Current freeze authority
+    pub authority: Signer<'info>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Context for managing delegated minters
+#[derive(Accounts)]
+pub struct ManageMinters<'info> {
+    /// The MDNX token
+    #[account(mut)]
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
     /// Authority
     pub authority: Signer<'info>,
 } 
\ No newline at end of file
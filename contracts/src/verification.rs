@@ -1,5 +1,78 @@
 use anchor_lang::prelude::*;
 use crate::errors::ErrorCode;
+use crate::model_registry::{incremental_mean, sha256_seed, validate_sha256};
+use crate::contribution::BPS_DENOMINATOR;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::token::MdnxToken;
+use crate::staking::StakeAccount;
+use crate::config::{Config, CONFIG_SEED};
+
+/// Seed for verification PDAs (one per data hash and verifier)
+pub const VERIFICATION_SEED: &[u8] = b"verify";
+
+/// Seed for verification aggregate PDAs (one per data hash)
+pub const VERIFICATION_AGGREGATE_SEED: &[u8] = b"verification_aggregate";
+
+/// Maximum number of votes a consensus verification can collect
+pub const MAX_CONSENSUS_VOTES: usize = 10;
+
+/// Seed for the expert registry PDA
+pub const EXPERT_REGISTRY_SEED: &[u8] = b"expert_registry";
+
+/// Maximum number of credentialed experts
+pub const MAX_EXPERTS: usize = 10;
+
+/// Maximum length of an expert's specialty
+pub const MAX_EXPERT_SPECIALTY_LEN: usize = 32;
+
+/// Seed for verifier profile PDAs
+pub const VERIFIER_PROFILE_SEED: &[u8] = b"verifier_profile";
+
+/// Seed for the global verification method policy PDA
+pub const METHOD_POLICY_SEED: &[u8] = b"method_policy";
+
+/// Maximum number of verification methods with a confidence floor
+pub const MAX_METHOD_POLICIES: usize = 16;
+
+/// Maximum length of a verification method covered by a policy
+pub const MAX_POLICY_METHOD_LEN: usize = 32;
+
+/// Weight given to verifications without a stake behind them
+pub const BASE_VERIFIER_WEIGHT: u64 = 1;
+
+/// Weight of a verifier's confidence: their staked MDNX, never below the base weight.
+/// A stake only counts if it is in the canonical token and stays locked through
+/// `locked_through`, the end of the verification's challenge window
+pub fn verifier_weight(
+    stake: &Option<Account<StakeAccount>>,
+    canonical_token: &Pubkey,
+    locked_through: i64,
+) -> Result<u64> {
+    let stake = match stake {
+        Some(stake) => stake,
+        None => return Ok(BASE_VERIFIER_WEIGHT),
+    };
+    
+    if stake.mdnx_token != *canonical_token {
+        return Err(ErrorCode::InvalidTokenAccount.into());
+    }
+    
+    if stake.locked_until < locked_through {
+        return Err(ErrorCode::StakeLockTooShort.into());
+    }
+    
+    Ok(stake.amount.max(BASE_VERIFIER_WEIGHT))
+}
+
+/// The canonical MDNX token named in the config, or the default key while the
+/// config is uninitialized (matching no stake)
+fn canonical_token(config: &AccountInfo) -> Result<Pubkey> {
+    if config.data_is_empty() {
+        return Ok(Pubkey::default());
+    }
+    
+    Ok(Account::<Config>::try_from(config)?.mdnx_token)
+}
 
 /// Verification data structure
 #[account]
@@ -13,8 +86,8 @@ pub struct Verification {
     /// Verification method used
     pub verification_method: String,
     
-    /// Confidence score (0.0-1.0)
-    pub confidence_score: f64,
+    /// Confidence score in basis points (0-10000)
+    pub confidence_score: u16,
     
     /// Verifier's public key
     pub verifier: Pubkey,
@@ -30,6 +103,30 @@ pub struct Verification {
     
     /// Verification result details
     pub result_details: String,
+    
+    /// Current status of the verification
+    pub status: VerificationStatus,
+    
+    /// Expiry timestamp (None if the verification never expires)
+    pub valid_until: Option<i64>,
+    
+    /// Bump of the verification PDA
+    pub bump: u8,
+    
+    /// Contribution the verification validates (if applicable)
+    pub contribution: Option<Pubkey>,
+    
+    /// Whether the verification has been counted towards its model
+    pub finalized: bool,
+    
+    /// Seconds after creation during which the verification can be challenged
+    pub challenge_period: i64,
+    
+    /// Who challenged the verification, blocking its finalization
+    pub challenged_by: Option<Pubkey>,
+    
+    /// Weight of the confidence score in the model average, applied on finalization
+    pub confidence_weight: u64,
 }
 
 /// Type of verification
@@ -48,49 +145,418 @@ pub enum VerificationType {
     ExpertReview,
 }
 
+/// Status of a verification record
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum VerificationStatus {
+    /// Verification stands
+    Active,
+    
+    /// Verification was retracted by its verifier
+    Revoked,
+}
+
 impl Verification {
     pub const LEN: usize = 8 + // discriminator
         4 + // verification_type (enum)
         64 + // data_hash (string)
         64 + // verification_method (string)
-        8 + // confidence_score (f64)
+        2 + // confidence_score (u16 basis points)
         32 + // verifier
         33 + // model (Option<Pubkey>)
         8 + // created_at
         512 + // metadata (string)
-        512; // result_details (string)
+        512 + // result_details (string)
+        1 + // status (enum)
+        9 + // valid_until (Option<i64>)
+        1 + // bump
+        33 + // contribution (Option<Pubkey>)
+        1 + // finalized
+        8 + // challenge_period
+        33 + // challenged_by (Option<Pubkey>)
+        8; // confidence_weight
+    
+    /// Whether the verification is active and unexpired at the given time
+    pub fn is_valid_at(&self, timestamp: i64) -> bool {
+        self.status == VerificationStatus::Active
+            && self.valid_until.map_or(true, |valid_until| timestamp < valid_until)
+    }
+    
+    /// Whether the verification is currently valid
+    pub fn is_currently_valid(&self) -> Result<bool> {
+        Ok(self.is_valid_at(Clock::get()?.unix_timestamp))
+    }
+    
+    /// Whether the challenge window is still open at the given time
+    pub fn in_challenge_period(&self, timestamp: i64) -> bool {
+        timestamp < self.created_at.saturating_add(self.challenge_period)
+    }
+}
+
+/// Per-verifier track record across all verifications
+#[account]
+pub struct VerifierProfile {
+    /// Verifier's public key
+    pub verifier: Pubkey,
+    
+    /// Number of verifications performed
+    pub total_verifications: u64,
+    
+    /// Average confidence score across those verifications (basis points)
+    pub average_confidence: u16,
+    
+    /// Number of medical data verifications
+    pub medical_data_count: u64,
+    
+    /// Number of analysis result verifications
+    pub analysis_result_count: u64,
+    
+    /// Number of model output verifications
+    pub model_output_count: u64,
+    
+    /// Number of expert review verifications
+    pub expert_review_count: u64,
+    
+    /// Bump of the verifier profile PDA
+    pub bump: u8,
+}
+
+impl VerifierProfile {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // verifier
+        8 + // total_verifications
+        2 + // average_confidence (basis points)
+        8 + // medical_data_count
+        8 + // analysis_result_count
+        8 + // model_output_count
+        8 + // expert_review_count
+        1; // bump
+    
+    /// Add a verification to the track record, creating the profile on the
+    /// verifier's first verification
+    pub fn record(
+        &mut self,
+        verifier: Pubkey,
+        bump: u8,
+        verification_type: &VerificationType,
+        confidence_score: u16,
+    ) -> Result<()> {
+        if self.verifier == Pubkey::default() {
+            self.verifier = verifier;
+            self.bump = bump;
+        }
+        
+        self.average_confidence = incremental_mean(
+            self.average_confidence,
+            self.total_verifications,
+            confidence_score,
+        );
+        self.total_verifications = self.total_verifications
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        let type_count = match verification_type {
+            VerificationType::MedicalData => &mut self.medical_data_count,
+            VerificationType::AnalysisResult => &mut self.analysis_result_count,
+            VerificationType::ModelOutput => &mut self.model_output_count,
+            VerificationType::ExpertReview => &mut self.expert_review_count,
+        };
+        *type_count = type_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(())
+    }
+}
+
+/// Consensus confidence for a data hash across all of its verifiers
+#[account]
+pub struct VerificationAggregate {
+    /// Data or analysis hash (SHA-256)
+    pub data_hash: String,
+    
+    /// Number of active verifications of the hash
+    pub verification_count: u64,
+    
+    /// Sum of their confidence scores (basis points)
+    pub total_confidence: u64,
+    
+    /// Bump of the aggregate PDA
+    pub bump: u8,
+}
+
+impl VerificationAggregate {
+    pub const LEN: usize = 8 + // discriminator
+        4 + 64 + // data_hash (string)
+        8 + // verification_count
+        8 + // total_confidence
+        1; // bump
+    
+    /// Add a verification's confidence, creating the aggregate on the
+    /// hash's first verification
+    pub fn record(&mut self, data_hash: &str, bump: u8, confidence_score: u16) -> Result<()> {
+        if self.data_hash.is_empty() {
+            self.data_hash = data_hash.to_string();
+            self.bump = bump;
+        }
+        
+        self.verification_count = self.verification_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.total_confidence = self.total_confidence
+            .checked_add(confidence_score as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(())
+    }
+    
+    /// Remove a revoked verification's confidence
+    pub fn remove(&mut self, confidence_score: u16) -> Result<()> {
+        self.verification_count = self.verification_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.total_confidence = self.total_confidence
+            .checked_sub(confidence_score as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        Ok(())
+    }
+    
+    /// Mean confidence of the active verifications (0 if there are none)
+    pub fn mean_confidence(&self) -> u16 {
+        if self.verification_count == 0 {
+            return 0;
+        }
+        (self.total_confidence / self.verification_count) as u16
+    }
+}
+
+/// Consensus confidence for a data hash, returned by get_verification_aggregate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct AggregateConfidence {
+    /// Number of active verifications of the hash
+    pub verification_count: u64,
+    
+    /// Mean confidence across them (basis points)
+    pub mean_confidence: u16,
+}
+
+/// Verification requiring agreement among several verifiers
+#[account]
+pub struct ConsensusVerification {
+    /// Data or analysis hash (SHA-256)
+    pub data_hash: String,
+    
+    /// Model the consensus verifies
+    pub model: Pubkey,
+    
+    /// Number of votes required to finalize
+    pub quorum: u8,
+    
+    /// Votes submitted so far
+    pub votes: Vec<ConsensusVote>,
+    
+    /// Mean confidence of all votes in basis points (set on finalization)
+    pub aggregated_confidence: u16,
+    
+    /// Whether the consensus has been finalized
+    pub finalized: bool,
+    
+    /// Creation timestamp
+    pub created_at: i64,
+    
+    /// Finalization timestamp
+    pub finalized_at: i64,
+}
+
+/// A single verifier's vote in a consensus verification
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ConsensusVote {
+    /// Verifier's public key
+    pub verifier: Pubkey,
+    
+    /// Verifier's confidence score in basis points (0-10000)
+    pub confidence_score: u16,
+}
+
+impl ConsensusVerification {
+    pub const LEN: usize = 8 + // discriminator
+        64 + // data_hash (string)
+        32 + // model
+        1 + // quorum
+        4 + (34 * MAX_CONSENSUS_VOTES) + // votes (vec of ConsensusVote)
+        2 + // aggregated_confidence (u16 basis points)
+        1 + // finalized
+        8 + // created_at
+        8; // finalized_at
+    
+    /// Check whether a verifier has already voted
+    pub fn has_voted(&self, verifier: &Pubkey) -> bool {
+        self.votes.iter().any(|vote| vote.verifier == *verifier)
+    }
+}
+
+/// Registry of credentialed experts approved by the MDNX authority
+#[account]
+pub struct ExpertRegistry {
+    /// The MDNX token whose authority manages this registry
+    pub mdnx_token: Pubkey,
+    
+    /// Credentialed experts
+    pub experts: Vec<Expert>,
+    
+    /// Bump of the expert registry PDA
+    pub bump: u8,
+}
+
+/// A credentialed expert and their domain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct Expert {
+    /// Expert's public key
+    pub expert: Pubkey,
+    
+    /// Medical specialty (e.g., "radiology", "pathology")
+    pub specialty: String,
+}
+
+impl ExpertRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mdnx_token
+        4 + MAX_EXPERTS * (32 + 4 + MAX_EXPERT_SPECIALTY_LEN) + // experts (Vec<Expert>)
+        1; // bump
+    
+    /// Whether the given key is a registered expert
+    pub fn is_expert(&self, key: &Pubkey) -> bool {
+        self.experts.iter().any(|entry| entry.expert == *key)
+    }
+}
+
+/// Minimum confidence scores required by particular verification methods,
+/// managed by the config admin
+#[account]
+pub struct MethodPolicyRegistry {
+    /// Methods with a confidence floor
+    pub policies: Vec<MethodPolicy>,
+    
+    /// Bump of the method policy PDA
+    pub bump: u8,
+}
+
+/// Confidence floor for one verification method
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct MethodPolicy {
+    /// Verification method the floor applies to
+    pub method: String,
+    
+    /// Minimum confidence score (basis points)
+    pub min_confidence: u16,
+}
+
+impl MethodPolicyRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        4 + MAX_METHOD_POLICIES * (4 + MAX_POLICY_METHOD_LEN + 2) + // policies (Vec<MethodPolicy>)
+        1; // bump
+    
+    /// Minimum confidence for the method (0 for methods without a policy)
+    pub fn min_confidence(&self, method: &str) -> u16 {
+        self.policies
+            .iter()
+            .find(|policy| policy.method == method)
+            .map_or(0, |policy| policy.min_confidence)
+    }
+}
+
+/// Reject confidence scores below the verification method's floor, treating an
+/// uninitialized policy registry as empty
+fn check_method_confidence(
+    method_policy: &AccountInfo,
+    verification_method: &str,
+    confidence_score: u16,
+) -> Result<()> {
+    if method_policy.data_is_empty() {
+        return Ok(());
+    }
+    
+    let registry = Account::<MethodPolicyRegistry>::try_from(method_policy)?;
+    if confidence_score < registry.min_confidence(verification_method) {
+        return Err(ErrorCode::ConfidenceBelowThreshold.into());
+    }
+    
+    Ok(())
+}
+
+/// Reject confidence scores outside the bounds configured for the verification
+/// type, treating an uninitialized config as unbounded
+fn check_type_confidence(
+    config: &AccountInfo,
+    verification_type: &VerificationType,
+    confidence_score: u16,
+) -> Result<()> {
+    if config.data_is_empty() {
+        return Ok(());
+    }
+    
+    let config = Account::<Config>::try_from(config)?;
+    if !config.accepts_confidence(verification_type, confidence_score) {
+        return Err(ErrorCode::ConfidenceOutOfTypeBounds.into());
+    }
+    
+    Ok(())
 }
 
 /// Verification operation implementations
 pub mod verification_operations {
     use super::*;
+    use anchor_spl::token;
     
     /// Verify medical data
     pub fn verify_data(
         ctx: Context<crate::VerifyData>,
         data_hash: String,
         verification_method: String,
-        confidence_score: f64,
+        confidence_score: u16,
         metadata: String,
         result_details: String,
+        valid_until: Option<i64>,
     ) -> Result<()> {
         let verification = &mut ctx.accounts.verification;
         let verifier = &ctx.accounts.verifier;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
-        // Validate inputs
-        if data_hash.len() < 16 {
-            return Err(ErrorCode::InvalidDataHash.into());
+        // Each verifier can verify a given hash only once
+        if verification.verifier != Pubkey::default() {
+            return Err(ErrorCode::DataAlreadyVerified.into());
         }
         
+        // Validate inputs
+        validate_sha256(&data_hash)?;
+        
         if verification_method.is_empty() {
             return Err(ErrorCode::InvalidVerificationMethod.into());
         }
         
-        if confidence_score < 0.0 || confidence_score > 1.0 {
+        if confidence_score > BPS_DENOMINATOR {
             return Err(ErrorCode::InvalidConfidenceScore.into());
         }
         
+        check_method_confidence(&ctx.accounts.method_policy, &verification_method, confidence_score)?;
+        check_type_confidence(&ctx.accounts.config, &VerificationType::MedicalData, confidence_score)?;
+        
+        if valid_until.map_or(false, |valid_until| valid_until <= current_timestamp) {
+            return Err(ErrorCode::InvalidValidityPeriod.into());
+        }
+        
+        // Pay the verifier if the model charges a verification fee
+        let verification_fee = ctx.accounts.model.as_ref().map_or(0, |model| model.verification_fee);
+        collect_verification_fee(
+            verification_fee,
+            verifier,
+            &ctx.accounts.mdnx_token,
+            &ctx.accounts.requester,
+            &ctx.accounts.requester_token_account,
+            &ctx.accounts.verifier_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        
         // Initialize verification
         verification.verification_type = VerificationType::MedicalData;
         verification.data_hash = data_hash;
@@ -101,14 +567,30 @@ pub mod verification_operations {
         verification.created_at = current_timestamp;
         verification.metadata = metadata;
         verification.result_details = result_details;
+        verification.status = VerificationStatus::Active;
+        verification.valid_until = valid_until;
+        verification.bump = *ctx.bumps.get("verification").unwrap();
+        verification.contribution = ctx.accounts.contribution.as_ref().map(|contribution| contribution.key());
         
-        // If model is provided, update model verification count
-        if let Some(model_account) = &ctx.accounts.model {
-            let model = &mut model_account;
-            model.verification_count += 1;
-        }
+        // Add the verification to the verifier's track record
+        ctx.accounts.verifier_profile.record(
+            verifier.key(),
+            *ctx.bumps.get("verifier_profile").unwrap(),
+            &verification.verification_type,
+            confidence_score,
+        )?;
+        
+        // Fold the confidence into the hash's aggregate
+        ctx.accounts.verification_aggregate.record(
+            &verification.data_hash,
+            *ctx.bumps.get("verification_aggregate").unwrap(),
+            confidence_score,
+        )?;
         
-        msg!("Medical data verified: {}", data_hash);
+        // If model is provided, count the verification once it can no longer be challenged
+        count_verification(verification, ctx.accounts.model.as_deref_mut(), BASE_VERIFIER_WEIGHT, current_timestamp)?;
+        
+        msg!("Medical data verified: {}", verification.data_hash);
         Ok(())
     }
     
@@ -117,27 +599,50 @@ pub mod verification_operations {
         ctx: Context<crate::VerifyAnalysis>,
         data_hash: String,
         verification_method: String,
-        confidence_score: f64,
+        confidence_score: u16,
         metadata: String,
         result_details: String,
+        valid_until: Option<i64>,
     ) -> Result<()> {
         let verification = &mut ctx.accounts.verification;
         let verifier = &ctx.accounts.verifier;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
-        // Validate inputs
-        if data_hash.len() < 16 {
-            return Err(ErrorCode::InvalidDataHash.into());
+        // Each verifier can verify a given hash only once
+        if verification.verifier != Pubkey::default() {
+            return Err(ErrorCode::DataAlreadyVerified.into());
         }
         
+        // Validate inputs
+        validate_sha256(&data_hash)?;
+        
         if verification_method.is_empty() {
             return Err(ErrorCode::InvalidVerificationMethod.into());
         }
         
-        if confidence_score < 0.0 || confidence_score > 1.0 {
+        if confidence_score > BPS_DENOMINATOR {
             return Err(ErrorCode::InvalidConfidenceScore.into());
         }
         
+        check_method_confidence(&ctx.accounts.method_policy, &verification_method, confidence_score)?;
+        check_type_confidence(&ctx.accounts.config, &VerificationType::AnalysisResult, confidence_score)?;
+        
+        if valid_until.map_or(false, |valid_until| valid_until <= current_timestamp) {
+            return Err(ErrorCode::InvalidValidityPeriod.into());
+        }
+        
+        // Pay the verifier if the model charges a verification fee
+        let verification_fee = ctx.accounts.model.as_ref().map_or(0, |model| model.verification_fee);
+        collect_verification_fee(
+            verification_fee,
+            verifier,
+            &ctx.accounts.mdnx_token,
+            &ctx.accounts.requester,
+            &ctx.accounts.requester_token_account,
+            &ctx.accounts.verifier_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        
         // Initialize verification
         verification.verification_type = VerificationType::AnalysisResult;
         verification.data_hash = data_hash;
@@ -148,14 +653,30 @@ pub mod verification_operations {
         verification.created_at = current_timestamp;
         verification.metadata = metadata;
         verification.result_details = result_details;
+        verification.status = VerificationStatus::Active;
+        verification.valid_until = valid_until;
+        verification.bump = *ctx.bumps.get("verification").unwrap();
+        verification.contribution = ctx.accounts.contribution.as_ref().map(|contribution| contribution.key());
         
-        // If model is provided, update model verification count
-        if let Some(model_account) = &ctx.accounts.model {
-            let model = &mut model_account;
-            model.verification_count += 1;
-        }
+        // Add the verification to the verifier's track record
+        ctx.accounts.verifier_profile.record(
+            verifier.key(),
+            *ctx.bumps.get("verifier_profile").unwrap(),
+            &verification.verification_type,
+            confidence_score,
+        )?;
+        
+        // Fold the confidence into the hash's aggregate
+        ctx.accounts.verification_aggregate.record(
+            &verification.data_hash,
+            *ctx.bumps.get("verification_aggregate").unwrap(),
+            confidence_score,
+        )?;
+        
+        // If model is provided, count the verification once it can no longer be challenged
+        count_verification(verification, ctx.accounts.model.as_deref_mut(), BASE_VERIFIER_WEIGHT, current_timestamp)?;
         
-        msg!("Analysis result verified: {}", data_hash);
+        msg!("Analysis result verified: {}", verification.data_hash);
         Ok(())
     }
     
@@ -164,28 +685,56 @@ pub mod verification_operations {
         ctx: Context<VerifyModelOutput>,
         data_hash: String,
         verification_method: String,
-        confidence_score: f64,
+        confidence_score: u16,
         metadata: String,
         result_details: String,
+        valid_until: Option<i64>,
     ) -> Result<()> {
         let verification = &mut ctx.accounts.verification;
         let verifier = &ctx.accounts.verifier;
         let model = &mut ctx.accounts.model;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
-        // Validate inputs
-        if data_hash.len() < 16 {
-            return Err(ErrorCode::InvalidDataHash.into());
+        // Each verifier can verify a given hash only once
+        if verification.verifier != Pubkey::default() {
+            return Err(ErrorCode::DataAlreadyVerified.into());
+        }
+        
+        // Models with an allowlist only accept its verifiers
+        if !model.is_authorized_verifier(&verifier.key()) {
+            return Err(ErrorCode::NotAuthorizedVerifier.into());
         }
         
+        // Validate inputs
+        validate_sha256(&data_hash)?;
+        
         if verification_method.is_empty() {
             return Err(ErrorCode::InvalidVerificationMethod.into());
         }
         
-        if confidence_score < 0.0 || confidence_score > 1.0 {
+        if confidence_score > BPS_DENOMINATOR {
             return Err(ErrorCode::InvalidConfidenceScore.into());
         }
         
+        check_method_confidence(&ctx.accounts.method_policy, &verification_method, confidence_score)?;
+        check_type_confidence(&ctx.accounts.config, &VerificationType::ModelOutput, confidence_score)?;
+        
+        if valid_until.map_or(false, |valid_until| valid_until <= current_timestamp) {
+            return Err(ErrorCode::InvalidValidityPeriod.into());
+        }
+        
+        // Pay the verifier if the model charges a verification fee
+        let verification_fee = model.verification_fee;
+        collect_verification_fee(
+            verification_fee,
+            verifier,
+            &ctx.accounts.mdnx_token,
+            &ctx.accounts.requester,
+            &ctx.accounts.requester_token_account,
+            &ctx.accounts.verifier_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        
         // Initialize verification
         verification.verification_type = VerificationType::ModelOutput;
         verification.data_hash = data_hash;
@@ -196,11 +745,38 @@ pub mod verification_operations {
         verification.created_at = current_timestamp;
         verification.metadata = metadata;
         verification.result_details = result_details;
+        verification.status = VerificationStatus::Active;
+        verification.valid_until = valid_until;
+        verification.bump = *ctx.bumps.get("verification").unwrap();
+        verification.contribution = ctx.accounts.contribution.as_ref().map(|contribution| contribution.key());
+        
+        // Add the verification to the verifier's track record
+        ctx.accounts.verifier_profile.record(
+            verifier.key(),
+            *ctx.bumps.get("verifier_profile").unwrap(),
+            &verification.verification_type,
+            confidence_score,
+        )?;
+        
+        // Fold the confidence into the hash's aggregate
+        ctx.accounts.verification_aggregate.record(
+            &verification.data_hash,
+            *ctx.bumps.get("verification_aggregate").unwrap(),
+            confidence_score,
+        )?;
         
-        // Update model verification count
-        model.verification_count += 1;
+        // Count the verification towards the model, weighting by the verifier's stake
+        let locked_through = current_timestamp
+            .checked_add(model.verification_challenge_period)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let weight = verifier_weight(
+            &ctx.accounts.verifier_stake,
+            &canonical_token(&ctx.accounts.config)?,
+            locked_through,
+        )?;
+        count_verification(verification, Some(&mut **model), weight, current_timestamp)?;
         
-        msg!("Model output verified: {}", data_hash);
+        msg!("Model output verified: {}", verification.data_hash);
         Ok(())
     }
     
@@ -209,30 +785,50 @@ pub mod verification_operations {
         ctx: Context<ExpertVerification>,
         data_hash: String,
         verification_method: String,
-        confidence_score: f64,
+        confidence_score: u16,
         metadata: String,
         result_details: String,
+        valid_until: Option<i64>,
     ) -> Result<()> {
         let verification = &mut ctx.accounts.verification;
         let verifier = &ctx.accounts.verifier;
         let current_timestamp = Clock::get()?.unix_timestamp;
         
-        // Validate expert status (in a real system, would validate against a registry of experts)
-        // For demonstration, we just validate input parameters
+        // Each verifier can verify a given hash only once
+        if verification.verifier != Pubkey::default() {
+            return Err(ErrorCode::DataAlreadyVerified.into());
+        }
         
         // Validate inputs
-        if data_hash.len() < 16 {
-            return Err(ErrorCode::InvalidDataHash.into());
-        }
+        validate_sha256(&data_hash)?;
         
         if verification_method.is_empty() {
             return Err(ErrorCode::InvalidVerificationMethod.into());
         }
         
-        if confidence_score < 0.0 || confidence_score > 1.0 {
+        if confidence_score > BPS_DENOMINATOR {
             return Err(ErrorCode::InvalidConfidenceScore.into());
         }
         
+        check_method_confidence(&ctx.accounts.method_policy, &verification_method, confidence_score)?;
+        check_type_confidence(&ctx.accounts.config.to_account_info(), &VerificationType::ExpertReview, confidence_score)?;
+        
+        if valid_until.map_or(false, |valid_until| valid_until <= current_timestamp) {
+            return Err(ErrorCode::InvalidValidityPeriod.into());
+        }
+        
+        // Pay the verifier if the model charges a verification fee
+        let verification_fee = ctx.accounts.model.as_ref().map_or(0, |model| model.verification_fee);
+        collect_verification_fee(
+            verification_fee,
+            verifier,
+            &ctx.accounts.mdnx_token,
+            &ctx.accounts.requester,
+            &ctx.accounts.requester_token_account,
+            &ctx.accounts.verifier_token_account,
+            &ctx.accounts.token_program,
+        )?;
+        
         // Initialize verification
         verification.verification_type = VerificationType::ExpertReview;
         verification.data_hash = data_hash;
@@ -243,33 +839,530 @@ pub mod verification_operations {
         verification.created_at = current_timestamp;
         verification.metadata = metadata;
         verification.result_details = result_details;
+        verification.status = VerificationStatus::Active;
+        verification.valid_until = valid_until;
+        verification.bump = *ctx.bumps.get("verification").unwrap();
+        verification.contribution = ctx.accounts.contribution.as_ref().map(|contribution| contribution.key());
+        
+        // Add the verification to the verifier's track record
+        ctx.accounts.verifier_profile.record(
+            verifier.key(),
+            *ctx.bumps.get("verifier_profile").unwrap(),
+            &verification.verification_type,
+            confidence_score,
+        )?;
+        
+        // Fold the confidence into the hash's aggregate
+        ctx.accounts.verification_aggregate.record(
+            &verification.data_hash,
+            *ctx.bumps.get("verification_aggregate").unwrap(),
+            confidence_score,
+        )?;
+        
+        // If model is provided, count the verification, weighting by the verifier's stake
+        let challenge_period = ctx.accounts.model.as_ref().map_or(0, |model| model.verification_challenge_period);
+        let locked_through = current_timestamp
+            .checked_add(challenge_period)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let weight = verifier_weight(&ctx.accounts.verifier_stake, &ctx.accounts.config.mdnx_token, locked_through)?;
+        count_verification(verification, ctx.accounts.model.as_deref_mut(), weight, current_timestamp)?;
+        
+        msg!("Expert verification completed: {}", verification.data_hash);
+        Ok(())
+    }
+    
+    /// Count a new verification towards its model: immediately when the model
+    /// has no challenge period, otherwise deferred to finalize_verification
+    fn count_verification(
+        verification: &mut Verification,
+        model: Option<&mut crate::model_registry::ModelRegistry>,
+        weight: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        verification.confidence_weight = weight;
+        verification.challenged_by = None;
+        
+        match model {
+            Some(model) if model.verification_challenge_period > 0 => {
+                verification.challenge_period = model.verification_challenge_period;
+                verification.finalized = false;
+            }
+            Some(model) => {
+                verification.challenge_period = 0;
+                verification.finalized = true;
+                model.record_verification(verification.confidence_score, weight, current_timestamp)?;
+            }
+            None => {
+                verification.challenge_period = 0;
+                verification.finalized = true;
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Transfer the verification fee from the requester to the verifier (no-op when zero)
+    fn collect_verification_fee<'info>(
+        verification_fee: u64,
+        verifier: &Signer<'info>,
+        mdnx_token: &Option<Account<'info, MdnxToken>>,
+        requester: &Option<Signer<'info>>,
+        requester_token_account: &Option<Account<'info, TokenAccount>>,
+        verifier_token_account: &Option<Account<'info, TokenAccount>>,
+        token_program: &Option<Program<'info, Token>>,
+    ) -> Result<()> {
+        if verification_fee == 0 {
+            return Ok(());
+        }
+        
+        let (mdnx_token, requester, source, destination, token_program) = match (
+            mdnx_token,
+            requester,
+            requester_token_account,
+            verifier_token_account,
+            token_program,
+        ) {
+            (Some(mdnx_token), Some(requester), Some(source), Some(destination), Some(token_program)) => {
+                (mdnx_token, requester, source, destination, token_program)
+            }
+            _ => return Err(ErrorCode::MissingVerificationFeeAccounts.into()),
+        };
+        
+        // Token transfers are halted while the program is paused
+        if mdnx_token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        require_keys_eq!(source.owner, requester.key(), ErrorCode::InvalidTokenAccount);
+        require_keys_eq!(source.mint, mdnx_token.mint, ErrorCode::InvalidTokenAccount);
+        require_keys_eq!(destination.owner, verifier.key(), ErrorCode::InvalidTokenAccount);
+        require_keys_eq!(destination.mint, mdnx_token.mint, ErrorCode::InvalidTokenAccount);
+        
+        if verification_fee > source.amount {
+            return Err(ErrorCode::InsufficientTokenBalance.into());
+        }
+        
+        let transfer_ctx = CpiContext::new(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: source.to_account_info(),
+                to: destination.to_account_info(),
+                authority: requester.to_account_info(),
+            },
+        );
+        
+        token::transfer(transfer_ctx, verification_fee)
+    }
+    
+    /// Register a credentialed expert
+    pub fn register_expert(
+        ctx: Context<RegisterExpert>,
+        expert: Pubkey,
+        specialty: String,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.expert_registry;
+        
+        // Only authority (and multisig, if enabled) can manage experts
+        ctx.accounts.mdnx_token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        if specialty.is_empty() || specialty.len() > MAX_EXPERT_SPECIALTY_LEN {
+            return Err(ErrorCode::InvalidExpertSpecialty.into());
+        }
+        
+        if registry.is_expert(&expert) || registry.experts.len() >= MAX_EXPERTS {
+            return Err(ErrorCode::InvalidExpertList.into());
+        }
+        
+        registry.mdnx_token = ctx.accounts.mdnx_token.key();
+        registry.bump = *ctx.bumps.get("expert_registry").unwrap();
+        registry.experts.push(Expert {
+            expert,
+            specialty,
+        });
+        
+        msg!("Expert registered: {}", expert);
+        Ok(())
+    }
+    
+    /// Set the minimum confidence for a verification method (config admin only)
+    pub fn set_method_policy(
+        ctx: Context<SetMethodPolicy>,
+        method: String,
+        min_confidence: u16,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.method_policy;
+        
+        // Validate inputs
+        if method.is_empty() || method.len() > MAX_POLICY_METHOD_LEN || min_confidence > BPS_DENOMINATOR {
+            return Err(ErrorCode::InvalidMethodPolicy.into());
+        }
+        
+        registry.bump = *ctx.bumps.get("method_policy").unwrap();
+        match registry.policies.iter_mut().find(|policy| policy.method == method) {
+            Some(policy) => policy.min_confidence = min_confidence,
+            None => {
+                if registry.policies.len() >= MAX_METHOD_POLICIES {
+                    return Err(ErrorCode::InvalidMethodPolicy.into());
+                }
+                registry.policies.push(MethodPolicy { method: method.clone(), min_confidence });
+            }
+        }
+        
+        msg!("Minimum confidence for {} set to {}", method, min_confidence);
+        Ok(())
+    }
+    
+    /// Drop a verification method's confidence floor (config admin only)
+    pub fn remove_method_policy(
+        ctx: Context<RemoveMethodPolicy>,
+        method: String,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.method_policy;
+        
+        let index = registry.policies
+            .iter()
+            .position(|policy| policy.method == method)
+            .ok_or(ErrorCode::InvalidMethodPolicy)?;
+        
+        registry.policies.remove(index);
+        
+        msg!("Method policy removed: {}", method);
+        Ok(())
+    }
+    
+    /// Remove a credentialed expert
+    pub fn remove_expert(
+        ctx: Context<RemoveExpert>,
+        expert: Pubkey,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.expert_registry;
+        
+        // Only authority (and multisig, if enabled) can manage experts
+        ctx.accounts.mdnx_token.verify_authority(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.remaining_accounts
+        )?;
+        
+        let index = registry.experts
+            .iter()
+            .position(|entry| entry.expert == expert)
+            .ok_or(ErrorCode::InvalidExpertList)?;
+        
+        registry.experts.remove(index);
+        
+        msg!("Expert removed: {}", expert);
+        Ok(())
+    }
+    
+    /// Open a consensus verification requiring `quorum` votes
+    pub fn open_consensus(
+        ctx: Context<OpenConsensus>,
+        data_hash: String,
+        quorum: u8,
+    ) -> Result<()> {
+        let consensus = &mut ctx.accounts.consensus;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Validate inputs
+        validate_sha256(&data_hash)?;
+        
+        if quorum == 0 || quorum as usize > MAX_CONSENSUS_VOTES {
+            return Err(ErrorCode::InvalidQuorum.into());
+        }
+        
+        consensus.data_hash = data_hash;
+        consensus.model = ctx.accounts.model.key();
+        consensus.quorum = quorum;
+        consensus.votes = Vec::new();
+        consensus.aggregated_confidence = 0;
+        consensus.finalized = false;
+        consensus.created_at = current_timestamp;
+        consensus.finalized_at = 0;
+        
+        msg!("Consensus verification opened: {} (quorum {})", consensus.data_hash, quorum);
+        Ok(())
+    }
+    
+    /// Submit a verifier's vote to an open consensus verification
+    pub fn submit_consensus_vote(
+        ctx: Context<SubmitConsensusVote>,
+        confidence_score: u16,
+    ) -> Result<()> {
+        let consensus = &mut ctx.accounts.consensus;
+        let verifier = ctx.accounts.verifier.key();
+        
+        // Validate inputs
+        if consensus.finalized {
+            return Err(ErrorCode::ConsensusAlreadyFinalized.into());
+        }
+        
+        if confidence_score > BPS_DENOMINATOR {
+            return Err(ErrorCode::InvalidConfidenceScore.into());
+        }
+        
+        if consensus.has_voted(&verifier) {
+            return Err(ErrorCode::DuplicateConsensusVote.into());
+        }
+        
+        if consensus.votes.len() >= MAX_CONSENSUS_VOTES {
+            return Err(ErrorCode::InvalidQuorum.into());
+        }
+        
+        consensus.votes.push(ConsensusVote {
+            verifier,
+            confidence_score,
+        });
+        
+        msg!("Consensus vote submitted by {}", verifier);
+        Ok(())
+    }
+    
+    /// Finalize a consensus verification once its quorum is reached
+    pub fn finalize_consensus(ctx: Context<FinalizeConsensus>) -> Result<()> {
+        let consensus = &mut ctx.accounts.consensus;
+        let model = &mut ctx.accounts.model;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        if consensus.finalized {
+            return Err(ErrorCode::ConsensusAlreadyFinalized.into());
+        }
         
-        // If model is provided, update model verification count
-        if let Some(model_account) = &ctx.accounts.model {
-            let model = &mut model_account;
-            model.verification_count += 1;
+        if consensus.votes.len() < consensus.quorum as usize {
+            return Err(ErrorCode::QuorumNotReached.into());
         }
         
-        msg!("Expert verification completed: {}", data_hash);
+        // Aggregate the mean confidence across all votes
+        let total: u64 = consensus.votes.iter().map(|vote| vote.confidence_score as u64).sum();
+        consensus.aggregated_confidence = (total / consensus.votes.len() as u64) as u16;
+        consensus.finalized = true;
+        consensus.finalized_at = current_timestamp;
+        
+        model.record_verification(consensus.aggregated_confidence, BASE_VERIFIER_WEIGHT, current_timestamp)?;
+        
+        msg!(
+            "Consensus verification finalized: {} (confidence {})",
+            consensus.data_hash,
+            consensus.aggregated_confidence
+        );
+        Ok(())
+    }
+    
+    /// Revoke a verification found to be erroneous (original verifier only)
+    pub fn revoke_verification(ctx: Context<RevokeVerification>) -> Result<()> {
+        let verification = &mut ctx.accounts.verification;
+        
+        if verification.status == VerificationStatus::Revoked {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        // Decrement the linked model's verification count, if it was counted
+        if let Some(linked_model) = verification.model.filter(|_| verification.finalized) {
+            let model = ctx.accounts.model
+                .as_mut()
+                .ok_or(ErrorCode::ModelMismatch)?;
+            require_keys_eq!(model.key(), linked_model, ErrorCode::ModelMismatch);
+            model.verification_count = model.verification_count
+                .checked_sub(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+        
+        ctx.accounts.verification_aggregate.remove(verification.confidence_score)?;
+        verification.status = VerificationStatus::Revoked;
+        
+        msg!("Verification revoked: {}", verification.data_hash);
+        Ok(())
+    }
+    
+    /// Challenge a verification during its challenge period, blocking it from
+    /// counting towards the model until the challenge is resolved
+    pub fn challenge_verification(ctx: Context<ChallengeVerification>) -> Result<()> {
+        let verification = &mut ctx.accounts.verification;
+        let challenger = ctx.accounts.challenger.key();
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only the model authority or a staker in the canonical token can challenge
+        let canonical_token = canonical_token(&ctx.accounts.config)?;
+        let is_staker = ctx.accounts.challenger_stake
+            .as_ref()
+            .map_or(false, |stake| stake.mdnx_token == canonical_token && stake.amount > 0);
+        if challenger != ctx.accounts.model.authority && !is_staker {
+            return Err(ErrorCode::UnauthorizedAccess.into());
+        }
+        
+        if verification.finalized || verification.status != VerificationStatus::Active {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        if verification.challenged_by.is_some() {
+            return Err(ErrorCode::VerificationChallenged.into());
+        }
+        
+        if !verification.in_challenge_period(current_timestamp) {
+            return Err(ErrorCode::ChallengePeriodElapsed.into());
+        }
+        
+        verification.challenged_by = Some(challenger);
+        
+        msg!("Verification challenged: {}", verification.data_hash);
+        Ok(())
+    }
+    
+    /// Resolve a challenge (config admin only): upholding it revokes the
+    /// verification, dismissing it lets the verification be finalized
+    pub fn resolve_challenge(ctx: Context<ResolveChallenge>, uphold: bool) -> Result<()> {
+        let verification = &mut ctx.accounts.verification;
+        
+        if verification.finalized || verification.status != VerificationStatus::Active {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        if verification.challenged_by.is_none() {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        // An upheld challenge never counted towards the model, so only the aggregate unwinds
+        if uphold {
+            ctx.accounts.verification_aggregate.remove(verification.confidence_score)?;
+            verification.status = VerificationStatus::Revoked;
+        }
+        verification.challenged_by = None;
+        
+        msg!("Challenge resolved for verification {} (upheld: {})", verification.data_hash, uphold);
+        Ok(())
+    }
+    
+    /// Count an unchallenged verification towards its model once the
+    /// challenge period has elapsed (callable by anyone)
+    pub fn finalize_verification(ctx: Context<FinalizeVerification>) -> Result<()> {
+        let verification = &mut ctx.accounts.verification;
+        let model = &mut ctx.accounts.model;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        if verification.finalized || verification.status != VerificationStatus::Active {
+            return Err(ErrorCode::InvalidStatusTransition.into());
+        }
+        
+        if verification.challenged_by.is_some() {
+            return Err(ErrorCode::VerificationChallenged.into());
+        }
+        
+        if verification.in_challenge_period(current_timestamp) {
+            return Err(ErrorCode::ChallengePeriodActive.into());
+        }
+        
+        model.record_verification(
+            verification.confidence_score,
+            verification.confidence_weight,
+            current_timestamp,
+        )?;
+        verification.finalized = true;
+        
+        msg!("Verification finalized: {}", verification.data_hash);
+        Ok(())
+    }
+    
+    /// Publish a data hash's verification count and mean confidence as return data
+    pub fn get_verification_aggregate(ctx: Context<GetVerificationAggregate>) -> Result<()> {
+        let aggregate = &ctx.accounts.verification_aggregate;
+        
+        let summary = AggregateConfidence {
+            verification_count: aggregate.verification_count,
+            mean_confidence: aggregate.mean_confidence(),
+        };
+        anchor_lang::solana_program::program::set_return_data(&summary.try_to_vec()?);
+        
+        msg!("Aggregate for {}: {} verifications, mean confidence {}", aggregate.data_hash, summary.verification_count, summary.mean_confidence);
         Ok(())
     }
 }
 
 /// Context for verifying model output
 #[derive(Accounts)]
+#[instruction(
+    data_hash: String,
+    verification_method: String,
+    confidence_score: u16,
+    metadata: String,
+    result_details: String,
+    valid_until: Option<i64>
+)]
 pub struct VerifyModelOutput<'info> {
-    /// The verification account to create
-    #[account(init, payer = verifier, space = Verification::LEN)]
+    /// The verification PDA for this data hash and verifier
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = Verification::LEN,
+        seeds = [VERIFICATION_SEED, &sha256_seed(&data_hash), verifier.key().as_ref()],
+        bump
+    )]
     pub verification: Account<'info, Verification>,
     
+    /// Aggregate confidence across all verifiers of this data hash
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerificationAggregate::LEN,
+        seeds = [VERIFICATION_AGGREGATE_SEED, &sha256_seed(&data_hash)],
+        bump
+    )]
+    pub verification_aggregate: Account<'info, VerificationAggregate>,
+    
     /// The model that generated the output
     #[account(mut)]
     pub model: Account<'info, crate::model_registry::ModelRegistry>,
     
+    /// The contribution being validated (optional)
+    pub contribution: Option<Account<'info, crate::contribution::Contribution>>,
+    
     /// The verifier (payer)
     #[account(mut)]
     pub verifier: Signer<'info>,
     
+    /// The verifier's track record, created on their first verification
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerifierProfile::LEN,
+        seeds = [VERIFIER_PROFILE_SEED, verifier.key().as_ref()],
+        bump
+    )]
+    pub verifier_profile: Account<'info, VerifierProfile>,
+    
+    /// Minimum confidence per verification method, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [METHOD_POLICY_SEED], bump)]
+    pub method_policy: UncheckedAccount<'info>,
+    
+    /// Global config supplying per-type confidence bounds, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    
+    /// The verifier's stake in the canonical token, locked through the challenge
+    /// window, weighting their confidence in the model average (optional)
+    #[account(constraint = verifier_stake.owner == verifier.key() @ ErrorCode::UnauthorizedAccess)]
+    pub verifier_stake: Option<Account<'info, StakeAccount>>,
+    
+    /// The MDNX token (required when the model charges a verification fee)
+    pub mdnx_token: Option<Account<'info, MdnxToken>>,
+    
+    /// Requester paying the verification fee
+    pub requester: Option<Signer<'info>>,
+    
+    /// Requester's token account paying the verification fee
+    #[account(mut)]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Verifier's token account receiving the verification fee
+    #[account(mut)]
+    pub verifier_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Token program
+    pub token_program: Option<Program<'info, Token>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
@@ -279,21 +1372,343 @@ pub struct VerifyModelOutput<'info> {
 
 /// Context for expert verification
 #[derive(Accounts)]
+#[instruction(
+    data_hash: String,
+    verification_method: String,
+    confidence_score: u16,
+    metadata: String,
+    result_details: String,
+    valid_until: Option<i64>
+)]
 pub struct ExpertVerification<'info> {
-    /// The verification account to create
-    #[account(init, payer = verifier, space = Verification::LEN)]
+    /// The verification PDA for this data hash and verifier
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = Verification::LEN,
+        seeds = [VERIFICATION_SEED, &sha256_seed(&data_hash), verifier.key().as_ref()],
+        bump
+    )]
     pub verification: Account<'info, Verification>,
     
+    /// Aggregate confidence across all verifiers of this data hash
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerificationAggregate::LEN,
+        seeds = [VERIFICATION_AGGREGATE_SEED, &sha256_seed(&data_hash)],
+        bump
+    )]
+    pub verification_aggregate: Account<'info, VerificationAggregate>,
+    
     /// The model used (optional)
+    #[account(mut)]
     pub model: Option<Account<'info, crate::model_registry::ModelRegistry>>,
     
-    /// The expert verifier (payer)
-    #[account(mut)]
+    /// Registry of credentialed experts for the canonical MDNX token
+    #[account(
+        seeds = [EXPERT_REGISTRY_SEED, config.mdnx_token.as_ref()],
+        bump = expert_registry.bump
+    )]
+    pub expert_registry: Account<'info, ExpertRegistry>,
+    
+    /// The contribution being validated (optional, must belong to the canonical token)
+    #[account(
+        constraint = contribution.mdnx_token == config.mdnx_token @ ErrorCode::InvalidTokenAccount
+    )]
+    pub contribution: Option<Account<'info, crate::contribution::Contribution>>,
+    
+    /// The expert verifier (payer, must be in the expert registry)
+    #[account(
+        mut,
+        constraint = expert_registry.is_expert(&verifier.key()) @ ErrorCode::NotRegisteredExpert
+    )]
     pub verifier: Signer<'info>,
     
+    /// The verifier's track record, created on their first verification
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerifierProfile::LEN,
+        seeds = [VERIFIER_PROFILE_SEED, verifier.key().as_ref()],
+        bump
+    )]
+    pub verifier_profile: Account<'info, VerifierProfile>,
+    
+    /// Minimum confidence per verification method, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [METHOD_POLICY_SEED], bump)]
+    pub method_policy: UncheckedAccount<'info>,
+    
+    /// Global config naming the canonical MDNX token and supplying per-type confidence bounds
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    /// The verifier's stake in the canonical token, locked through the challenge
+    /// window, weighting their confidence in the model average (optional)
+    #[account(constraint = verifier_stake.owner == verifier.key() @ ErrorCode::UnauthorizedAccess)]
+    pub verifier_stake: Option<Account<'info, StakeAccount>>,
+    
+    /// The MDNX token (required when the model charges a verification fee)
+    pub mdnx_token: Option<Account<'info, MdnxToken>>,
+    
+    /// Requester paying the verification fee
+    pub requester: Option<Signer<'info>>,
+    
+    /// Requester's token account paying the verification fee
+    #[account(mut)]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Verifier's token account receiving the verification fee
+    #[account(mut)]
+    pub verifier_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Token program
+    pub token_program: Option<Program<'info, Token>>,
+    
     /// System program
     pub system_program: Program<'info, System>,
     
     /// Rent sysvar
     pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for revoking a verification
+#[derive(Accounts)]
+pub struct RevokeVerification<'info> {
+    /// The verification to revoke
+    #[account(
+        mut,
+        constraint = verification.verifier == verifier.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub verification: Account<'info, Verification>,
+    
+    /// Aggregate confidence for the verification's data hash
+    #[account(
+        mut,
+        seeds = [VERIFICATION_AGGREGATE_SEED, &sha256_seed(&verification.data_hash)],
+        bump = verification_aggregate.bump
+    )]
+    pub verification_aggregate: Account<'info, VerificationAggregate>,
+    
+    /// The model linked to the verification (required if one is linked)
+    #[account(mut)]
+    pub model: Option<Account<'info, crate::model_registry::ModelRegistry>>,
+    
+    /// The original verifier
+    pub verifier: Signer<'info>,
+}
+
+/// Context for challenging a verification
+#[derive(Accounts)]
+pub struct ChallengeVerification<'info> {
+    /// The verification being challenged
+    #[account(
+        mut,
+        constraint = verification.model == Some(model.key()) @ ErrorCode::ModelMismatch
+    )]
+    pub verification: Account<'info, Verification>,
+    
+    /// The model the verification counts towards
+    pub model: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// Global config naming the canonical MDNX token, checked once initialized
+    /// CHECK: PDA verified by seeds; deserialized only when initialized
+    #[account(seeds = [CONFIG_SEED], bump)]
+    pub config: UncheckedAccount<'info>,
+    
+    /// The challenger's stake in the canonical token (required unless the
+    /// challenger is the model authority)
+    #[account(constraint = challenger_stake.owner == challenger.key() @ ErrorCode::UnauthorizedAccess)]
+    pub challenger_stake: Option<Account<'info, StakeAccount>>,
+    
+    /// The model authority or a staker raising the challenge
+    pub challenger: Signer<'info>,
+}
+
+/// Context for resolving a challenged verification
+#[derive(Accounts)]
+pub struct ResolveChallenge<'info> {
+    /// The challenged verification
+    #[account(mut)]
+    pub verification: Account<'info, Verification>,
+    
+    /// Aggregate confidence for the verification's data hash
+    #[account(
+        mut,
+        seeds = [VERIFICATION_AGGREGATE_SEED, &sha256_seed(&verification.data_hash)],
+        bump = verification_aggregate.bump
+    )]
+    pub verification_aggregate: Account<'info, VerificationAggregate>,
+    
+    /// The global config
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAccess
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Config admin
+    pub admin: Signer<'info>,
+}
+
+/// Context for finalizing a verification after its challenge period
+#[derive(Accounts)]
+pub struct FinalizeVerification<'info> {
+    /// The verification to finalize
+    #[account(
+        mut,
+        constraint = verification.model == Some(model.key()) @ ErrorCode::ModelMismatch
+    )]
+    pub verification: Account<'info, Verification>,
+    
+    /// The model the verification counts towards
+    #[account(mut)]
+    pub model: Account<'info, crate::model_registry::ModelRegistry>,
+}
+
+/// Context for reading a data hash's verification aggregate
+#[derive(Accounts)]
+pub struct GetVerificationAggregate<'info> {
+    /// Aggregate to read
+    pub verification_aggregate: Account<'info, VerificationAggregate>,
+}
+
+/// Context for opening a consensus verification
+#[derive(Accounts)]
+pub struct OpenConsensus<'info> {
+    /// The consensus verification account to create
+    #[account(init, payer = payer, space = ConsensusVerification::LEN)]
+    pub consensus: Account<'info, ConsensusVerification>,
+    
+    /// The model being verified
+    pub model: Account<'info, crate::model_registry::ModelRegistry>,
+    
+    /// The payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for submitting a consensus vote
+#[derive(Accounts)]
+pub struct SubmitConsensusVote<'info> {
+    /// The consensus verification being voted on
+    #[account(mut)]
+    pub consensus: Account<'info, ConsensusVerification>,
+    
+    /// The voting verifier
+    pub verifier: Signer<'info>,
+}
+
+/// Context for finalizing a consensus verification
+#[derive(Accounts)]
+pub struct FinalizeConsensus<'info> {
+    /// The consensus verification to finalize
+    #[account(mut)]
+    pub consensus: Account<'info, ConsensusVerification>,
+    
+    /// The model the consensus verifies
+    #[account(
+        mut,
+        constraint = model.key() == consensus.model @ ErrorCode::ModelMismatch
+    )]
+    pub model: Account<'info, crate::model_registry::ModelRegistry>,
+}
+
+/// Context for registering an expert
+#[derive(Accounts)]
+pub struct RegisterExpert<'info> {
+    /// The expert registry PDA for the MDNX token
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ExpertRegistry::LEN,
+        seeds = [EXPERT_REGISTRY_SEED, mdnx_token.key().as_ref()],
+        bump
+    )]
+    pub expert_registry: Account<'info, ExpertRegistry>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Authority (payer)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for removing an expert
+#[derive(Accounts)]
+pub struct RemoveExpert<'info> {
+    /// The expert registry PDA for the MDNX token
+    #[account(
+        mut,
+        seeds = [EXPERT_REGISTRY_SEED, mdnx_token.key().as_ref()],
+        bump = expert_registry.bump
+    )]
+    pub expert_registry: Account<'info, ExpertRegistry>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Authority
+    pub authority: Signer<'info>,
+}
+
+/// Context for setting a verification method's confidence floor
+#[derive(Accounts)]
+pub struct SetMethodPolicy<'info> {
+    /// The global method policy PDA
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MethodPolicyRegistry::LEN,
+        seeds = [METHOD_POLICY_SEED],
+        bump
+    )]
+    pub method_policy: Account<'info, MethodPolicyRegistry>,
+    
+    /// The global config naming the admin
+    #[account(
+        seeds = [crate::config::CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAccess
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Config admin (payer)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for removing a verification method's confidence floor
+#[derive(Accounts)]
+pub struct RemoveMethodPolicy<'info> {
+    /// The global method policy PDA
+    #[account(
+        mut,
+        seeds = [METHOD_POLICY_SEED],
+        bump = method_policy.bump
+    )]
+    pub method_policy: Account<'info, MethodPolicyRegistry>,
+    
+    /// The global config naming the admin
+    #[account(
+        seeds = [crate::config::CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAccess
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Config admin
+    pub admin: Signer<'info>,
 } 
\ No newline at end of file
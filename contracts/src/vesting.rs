@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::ErrorCode;
+use crate::token::{transfer_from_treasury, MdnxToken};
+
+/// Vesting schedule data structure
+#[account]
+pub struct VestingSchedule {
+    /// The MDNX token the schedule pays out
+    pub mdnx_token: Pubkey,
+    
+    /// Beneficiary receiving the vested tokens
+    pub beneficiary: Pubkey,
+    
+    /// Total amount of tokens to vest
+    pub total_amount: u64,
+    
+    /// Vesting start timestamp
+    pub start_ts: i64,
+    
+    /// Nothing can be claimed before this timestamp
+    pub cliff_ts: i64,
+    
+    /// Timestamp at which the full amount is unlocked
+    pub end_ts: i64,
+    
+    /// Amount already claimed
+    pub claimed_amount: u64,
+    
+    /// Creation timestamp
+    pub created_at: i64,
+}
+
+impl VestingSchedule {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mdnx_token
+        32 + // beneficiary
+        8 + // total_amount
+        8 + // start_ts
+        8 + // cliff_ts
+        8 + // end_ts
+        8 + // claimed_amount
+        8; // created_at
+    
+    /// Amount linearly unlocked at the given timestamp
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        if now < self.cliff_ts {
+            return Ok(0);
+        }
+        
+        if now >= self.end_ts {
+            return Ok(self.total_amount);
+        }
+        
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        let vested = (self.total_amount as u128)
+            .checked_mul(elapsed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            / duration;
+        
+        Ok((vested as u64).min(self.total_amount))
+    }
+}
+
+/// Vesting operation implementations
+pub mod vesting_operations {
+    use super::*;
+    
+    /// Create a vesting schedule for a beneficiary
+    pub fn create_vesting(
+        ctx: Context<CreateVesting>,
+        beneficiary: Pubkey,
+        total_amount: u64,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        let schedule = &mut ctx.accounts.vesting_schedule;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        // Only the token authority can create vesting schedules
+        require_keys_eq!(
+            ctx.accounts.mdnx_token.authority,
+            ctx.accounts.authority.key(),
+            ErrorCode::UnauthorizedAccess
+        );
+        
+        // Validate schedule
+        if total_amount == 0 || start_ts >= end_ts || cliff_ts < start_ts || cliff_ts > end_ts {
+            return Err(ErrorCode::InvalidVestingSchedule.into());
+        }
+        
+        // Initialize schedule
+        schedule.mdnx_token = ctx.accounts.mdnx_token.key();
+        schedule.beneficiary = beneficiary;
+        schedule.total_amount = total_amount;
+        schedule.start_ts = start_ts;
+        schedule.cliff_ts = cliff_ts;
+        schedule.end_ts = end_ts;
+        schedule.claimed_amount = 0;
+        schedule.created_at = current_timestamp;
+        
+        msg!("Vesting schedule created for {}: {} MDNX", beneficiary, total_amount);
+        Ok(())
+    }
+    
+    /// Claim the currently unlocked portion of a vesting schedule
+    pub fn claim_vested(
+        ctx: Context<ClaimVested>,
+    ) -> Result<()> {
+        let schedule = &mut ctx.accounts.vesting_schedule;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        
+        if ctx.accounts.mdnx_token.is_paused {
+            return Err(ErrorCode::ProgramPaused.into());
+        }
+        
+        // Reject claims before the cliff
+        if current_timestamp < schedule.cliff_ts {
+            return Err(ErrorCode::VestingCliffNotReached.into());
+        }
+        
+        // Compute the claimable amount
+        let vested = schedule.vested_amount(current_timestamp)?;
+        let claimable = vested
+            .checked_sub(schedule.claimed_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        if claimable == 0 {
+            return Err(ErrorCode::NothingToClaim.into());
+        }
+        
+        // Transfer tokens from treasury to beneficiary
+        transfer_from_treasury(
+            ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.treasury,
+            ctx.accounts.treasury_authority.as_ref().map(|authority| authority.to_account_info()),
+            ctx.accounts.beneficiary_token_account.to_account_info(),
+            ctx.accounts.mdnx_token.treasury_bump,
+            claimable,
+        )?;
+        
+        // Update claimed amount, never exceeding the total
+        schedule.claimed_amount = schedule.claimed_amount
+            .checked_add(claimable)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .min(schedule.total_amount);
+        
+        msg!("Claimed {} vested MDNX tokens", claimable);
+        Ok(())
+    }
+}
+
+/// Context for creating a vesting schedule
+#[derive(Accounts)]
+pub struct CreateVesting<'info> {
+    /// The vesting schedule account to create
+    #[account(init, payer = authority, space = VestingSchedule::LEN)]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Token authority (payer)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for claiming vested tokens
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    /// The vesting schedule
+    #[account(
+        mut,
+        has_one = mdnx_token @ ErrorCode::InvalidTokenAccount,
+        has_one = beneficiary @ ErrorCode::UnauthorizedAccess
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+    
+    /// The MDNX token
+    pub mdnx_token: Account<'info, MdnxToken>,
+    
+    /// Treasury token account (source of vested tokens)
+    #[account(
+        mut,
+        constraint = treasury.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount,
+        constraint = treasury.key() == mdnx_token.treasury @ ErrorCode::InvalidTokenAccount
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    
+    /// Beneficiary's token account (destination)
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.mint == mdnx_token.mint @ ErrorCode::InvalidTokenAccount
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+    
+    /// Beneficiary
+    pub beneficiary: Signer<'info>,
+    
+    /// Owner of an externally held treasury authorizing the transfer (not
+    /// needed for the program-owned treasury)
+    pub treasury_authority: Option<Signer<'info>>,
+    
+    /// Token program
+    pub token_program: Program<'info, Token>,
+} 
\ No newline at end of file